@@ -7,27 +7,36 @@ impl Chroma {
     fn _mix(color1: &Color, color2: &Color, mode: Option<&str>, ratio: Option<f64>) -> Color {
         let mode = mode.unwrap_or("rgba");
         let ratio = ratio.unwrap_or(0.5);
-        let v1 = color1.mode(mode);
-        let v2 = color2.mode(mode);
-        let mut v3 = Vec::new();
-        for i in 0..v1.len() {
-            v3.push(v1[i] + (v2[i] - v1[i]) * ratio);
-        }
-        Color::vec_mode2color(v3, mode)
+        color1.lerp(color2, ratio, mode)
     }
 
+    /// Mix two colors half and half in `rgba`, see [`Color::lerp`].
+    ///
+    /// In every mode the alpha is mixed too, so mixing with a translucent color
+    /// gives a translucent color, e.g. in `lab` or `hsl`.
+    ///
+    /// ```
+    /// use chroma_rust::{Chroma, Color};
+    ///
+    /// let red = Color::from("red");
+    /// let transparent = Color::from("rgba(0, 0, 255, 0)");
+    /// assert_eq!(Chroma::mix_mode(&red, &transparent, "lab").alpha(), 0.5);
+    /// ```
     pub fn mix(color1: &Color, color2: &Color) -> Color {
         Chroma::_mix(color1, color2, None, None)
     }
 
+    /// Mix two colors half and half in the given mode, see [`Chroma::mix`].
     pub fn mix_mode(color1: &Color, color2: &Color, mode: &str) -> Color {
         Chroma::_mix(color1, color2, Some(mode), None)
     }
 
+    /// Mix two colors in `rgba` with the ratio of the second one, see [`Chroma::mix`].
     pub fn mix_ratio(color1: &Color, color2: &Color, ratio: f64) -> Color {
         Self::_mix(color1, color2, None, Some(ratio))
     }
 
+    /// Mix two colors in the given mode with the ratio of the second one, see [`Chroma::mix`].
     pub fn mix_mode_and_ratio(color1: &Color, color2: &Color, mode: &str, ratio: f64) -> Color {
         Self::_mix(color1, color2, Some(mode), Some(ratio))
     }
@@ -63,5 +72,28 @@ mod tests {
         let color2 = Color::from("green");
         let color3 = Chroma::mix_mode(&color1, &color2, "lab");
         assert_eq!(color3.hex(), "#a16b00");
+
+        let color1 = Color::from("red");
+        let color2 = Color::from("blue");
+        let color3 = Chroma::mix_mode(&color1, &color2, "hwb");
+        assert_eq!(color3.hex(), "#ff00ff");
+
+        let color1 = Color::from("red");
+        let color2 = Color::from("blue");
+        let color3 = Chroma::mix_mode(&color1, &color2, "ycbcr-bt709-limited");
+        assert_eq!(color3.hex(), "#800080");
+    }
+
+    #[test]
+    fn test_mix_alpha() {
+        let color1 = Color::from("rgb(255, 0, 0)");
+        let color2 = Color::from("rgba(0, 0, 255, 0)");
+        assert_eq!(Chroma::mix(&color1, &color2).alpha(), 0.5);
+        assert_eq!(Chroma::mix_mode(&color1, &color2, "lab").alpha(), 0.5);
+        assert_eq!(Chroma::mix_mode(&color1, &color2, "hsl").alpha(), 0.5);
+        assert_eq!(
+            Chroma::mix_mode_and_ratio(&color1, &color2, "lch", 0.25).alpha(),
+            0.75
+        );
     }
 }
@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::utils::round;
+use crate::Color;
+
+/// The red, green, blue (`0..255`) and alpha (`0..1`) channels of a color as floats.
+///
+/// It can be indexed and iterated, channels outside of their range are
+/// clamped when converting back to a [`Color`].
+///
+/// ```
+/// use chroma_rust::{Channels, Color};
+///
+/// let mut channels = Channels::from(&Color::from("#ff8000"));
+/// assert_eq!(channels[1], 128.);
+///
+/// channels[3] = 0.5;
+/// let sum: f64 = channels.into_iter().take(3).sum();
+/// assert_eq!(sum, 383.);
+/// assert_eq!(Color::from(channels).hex(), "#ff800080");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Channels(pub [f64; 4]);
+
+impl From<&Color> for Channels {
+    fn from(color: &Color) -> Self {
+        let (r, g, b, a) = color.rgba();
+        Channels([r as f64, g as f64, b as f64, a])
+    }
+}
+
+impl From<Channels> for Color {
+    fn from(channels: Channels) -> Self {
+        let [r, g, b, a] = channels.0;
+        let channel = |v: f64| v.round().clamp(0., 255.) as u8;
+        Color::new(channel(r), channel(g), channel(b), a.clamp(0., 1.))
+    }
+}
+
+impl Index<usize> for Channels {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for Channels {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        &mut self.0[index]
+    }
+}
+
+impl IntoIterator for Channels {
+    type Item = f64;
+    type IntoIter = core::array::IntoIter<f64, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Channels {
+    type Item = &'a f64;
+    type IntoIter = core::slice::Iter<'a, f64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Color {
+    /// Iterate over the channels of the color in the given mode, see [`Color::mode`].
+    /// Returns `None` if the mode is unknown.
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// let color = Color::from("#ff8000");
+    /// let rgba: Vec<f64> = color.channels("rgba").unwrap().collect();
+    /// assert_eq!(rgba, vec![255., 128., 0., 1.]);
+    /// assert_eq!(color.channels("hsl").map(Iterator::count), Some(3));
+    /// assert!(color.channels("unknown").is_none());
+    /// ```
+    pub fn channels(&self, mode: &str) -> Option<impl Iterator<Item = f64>> {
+        self.try_mode(mode).map(Vec::into_iter)
+    }
+}
+
+impl From<[u8; 3]> for Color {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Color::new(r, g, b, 1.)
+    }
+}
+
+/// The alpha is `0..255`, like in hex strings.
+impl From<[u8; 4]> for Color {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Color::new(r, g, b, a as f64 / 255.)
+    }
+}
+
+/// All channels are `0..1`, values outside of it are clamped.
+impl From<[f32; 4]> for Color {
+    fn from(rgba: [f32; 4]) -> Self {
+        let [r, g, b, a] = rgba.map(|v| v.clamp(0., 1.));
+        let channel = |v: f32| (v as f64 * 255.).round() as u8;
+        Color::new(channel(r), channel(g), channel(b), round(a as f64, 6))
+    }
+}
+
+impl From<&Color> for [u8; 3] {
+    fn from(color: &Color) -> Self {
+        let (r, g, b) = color.rgb();
+        [r, g, b]
+    }
+}
+
+impl From<&Color> for [u8; 4] {
+    fn from(color: &Color) -> Self {
+        let (r, g, b, a) = color.rgba();
+        [r, g, b, (a * 255.).round() as u8]
+    }
+}
+
+impl From<&Color> for [f32; 4] {
+    fn from(color: &Color) -> Self {
+        let (r, g, b, a) = color.rgba();
+        [r as f32 / 255., g as f32 / 255., b as f32 / 255., a as f32]
+    }
+}
+
+impl From<Color> for [u8; 3] {
+    fn from(color: Color) -> Self {
+        (&color).into()
+    }
+}
+
+impl From<Color> for [u8; 4] {
+    fn from(color: Color) -> Self {
+        (&color).into()
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        (&color).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channels() {
+        let color = Color::from("#ff8000");
+        let mut channels = color.channels("rgba").unwrap();
+        assert_eq!(channels.next(), Some(255.));
+        assert_eq!(channels.next(), Some(128.));
+        assert_eq!(channels.next(), Some(0.));
+        assert_eq!(channels.next(), Some(1.));
+        assert_eq!(channels.next(), None);
+        assert_eq!(color.channels("cmyk").unwrap().count(), 4);
+        assert_eq!(color.channels("display-p3").unwrap().count(), 3);
+        assert!(color.channels("cmy").is_none());
+        assert!(color.channels("").is_none());
+    }
+
+    #[test]
+    fn test_channel_array() {
+        let color = Color::new(255, 128, 0, 0.5);
+        let channels = Channels::from(&color);
+        assert_eq!(channels, Channels([255., 128., 0., 0.5]));
+        assert_eq!(channels[3], 0.5);
+        assert_eq!((&channels).into_iter().count(), 4);
+        assert_eq!(Color::from(channels), color);
+        assert_eq!(
+            Color::from(Channels([300., -1., 127.6, 2.])),
+            Color::new(255, 0, 128, 1.)
+        );
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let color = Color::new(255, 128, 0, 0.5);
+        assert_eq!(<[u8; 3]>::from(&color), [255, 128, 0]);
+        assert_eq!(<[u8; 4]>::from(&color), [255, 128, 0, 128]);
+        assert_eq!(<[f32; 4]>::from(&color), [1., 128. / 255., 0., 0.5]);
+
+        assert_eq!(Color::from([255, 128, 0]), Color::new(255, 128, 0, 1.));
+        assert_eq!(
+            Color::from([255, 128, 0, 128]),
+            Color::new(255, 128, 0, 128. / 255.)
+        );
+        assert_eq!(Color::from([1., 0.5, 0., 0.5]), color);
+        assert_eq!(Color::from([2., -1., 0., 0.3]), Color::new(255, 0, 0, 0.3));
+
+        let array: [f32; 4] = color.into();
+        assert_eq!(Color::from(array), color);
+    }
+
+    #[test]
+    fn test_u8_alpha_round_trip() {
+        for a in 0..=255 {
+            let color = Color::from([255, 128, 0, a]);
+            assert_eq!(color.alpha(), a as f64 / 255.);
+            assert_eq!(<[u8; 4]>::from(color), [255, 128, 0, a]);
+        }
+    }
+}
@@ -1,7 +1,15 @@
-use crate::utils::{conversion, parser};
+use alloc::string::ToString;
+use core::str::FromStr;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::utils::{conversion, parser, parser::ParseColorError};
+use crate::{
+    Adaptation, Cam16, GamutMapping, RgbSpace, ViewingConditions, WhitePoint, Xyz, W3CX11,
+};
 
 /// Color is a struct that represents a color in RGBA format.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Color {
     pub(crate) rgba: (u8, u8, u8, f64),
 }
@@ -14,41 +22,242 @@ impl Color {
     }
 }
 
-impl From<&str> for Color {
-    fn from(str: &str) -> Self {
+impl Color {
+    /// Parse a hex color with a `#`, `0x` or no prefix, e.g. `ff8000` or `#ff800080`.
+    ///
+    /// [`FromStr`] requires the prefix, so that words like `facade` aren't colors.
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// assert_eq!(Color::from_hex("ff8000").unwrap().hex(), "#ff8000");
+    /// assert!("ff8000".parse::<Color>().is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Color, ParseColorError> {
+        let (r, g, b, a) = conversion::hex::hex2rgb(hex)?;
+        Ok(Color::new(r, g, b, a))
+    }
+
+    /// Construct a color from the channels (`0..1`) of an RGB color space.
+    ///
+    /// Channels outside of the sRGB gamut are clipped.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, RgbSpace};
+    ///
+    /// let color = Color::from_rgb_space((0.9175, 0.2003, 0.1386), &RgbSpace::DISPLAY_P3);
+    /// assert_eq!(color.hex(), "#ff0000");
+    /// ```
+    pub fn from_rgb_space(rgb: (f64, f64, f64), space: &RgbSpace) -> Color {
+        let (r, g, b) = space.convert(rgb, &RgbSpace::SRGB);
+        Color::new(
+            (r * 255.).round() as u8,
+            (g * 255.).round() as u8,
+            (b * 255.).round() as u8,
+            1.0,
+        )
+    }
+
+    /// Construct a color from CIE-L*ab, bringing it into the sRGB gamut with the given method.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, GamutMapping};
+    ///
+    /// let color = Color::from_lab((53.24, 80.09, 67.2), GamutMapping::Css);
+    /// assert_eq!(color.hex(), "#ff0000");
+    /// ```
+    pub fn from_lab(lab: (f64, f64, f64), method: GamutMapping) -> Color {
+        Color::from_lab_with(lab, WhitePoint::D65, method)
+    }
+
+    /// Construct a color from CIE-L*ab relative to the given reference white,
+    /// the inverse of [`Color::lab_with`].
+    ///
+    /// ```
+    /// use chroma_rust::{Color, GamutMapping, WhitePoint};
+    ///
+    /// let color = Color::from_lab_with((54.29, 80.8, 69.89), WhitePoint::D50, GamutMapping::Css);
+    /// assert_eq!(color.hex(), "#ff0000");
+    /// ```
+    pub fn from_lab_with(lab: (f64, f64, f64), white: WhitePoint, method: GamutMapping) -> Color {
+        let xyz = conversion::lab::lab2xyz(lab, white);
+        let xyz = Adaptation::Bradford.adapt(xyz, white, WhitePoint::D65);
+        let rgb = conversion::gamut::map_xyz(xyz, &RgbSpace::SRGB, method);
+        Color::from_rgb_space(rgb, &RgbSpace::SRGB)
+    }
+
+    /// Construct a color from CIE LCh, bringing it into the sRGB gamut with the given method.
+    pub fn from_lch(lch: (f64, f64, f64), method: GamutMapping) -> Color {
+        Color::from_lab(conversion::lch::lch2lab(lch), method)
+    }
+
+    /// Construct a color from OKLab, bringing it into the sRGB gamut with the given method.
+    pub fn from_oklab(oklab: (f64, f64, f64), method: GamutMapping) -> Color {
+        Color::from_oklch(conversion::lch::lab2lch(oklab), method)
+    }
+
+    /// Construct a color from OKLCh, bringing it into the sRGB gamut with the given method.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, GamutMapping};
+    ///
+    /// // far outside of sRGB, the hue is kept while the chroma is reduced
+    /// let color = Color::from_oklch((0.7, 0.4, 150.), GamutMapping::Css);
+    /// assert!((color.oklch().2 - 150.).abs() < 4.);
+    /// ```
+    pub fn from_oklch(oklch: (f64, f64, f64), method: GamutMapping) -> Color {
+        let rgb = conversion::gamut::map_oklch(oklch, &RgbSpace::SRGB, method);
+        Color::from_rgb_space(rgb, &RgbSpace::SRGB)
+    }
+
+    /// Construct a color from CAM16 seen in the given viewing conditions,
+    /// clipping values outside of the sRGB gamut.
+    pub fn from_cam16(cam: &Cam16, vc: &ViewingConditions) -> Color {
+        Color::from(Xyz::from(cam.xyz(vc)))
+    }
+
+    /// Construct a color from CAM16-UCS `J'`, `a'` and `b'` seen in the default
+    /// viewing conditions, clipping values outside of the sRGB gamut.
+    pub fn from_cam16_ucs(jab: (f64, f64, f64)) -> Color {
+        let vc = ViewingConditions::default();
+        Color::from_cam16(&Cam16::from_ucs(jab, &vc), &vc)
+    }
+
+    /// Construct a color from HCT (hue, chroma, tone).
+    ///
+    /// Outside of the sRGB gamut, the chroma is reduced while the hue and the tone are kept.
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// let color = Color::from_hct((27.408, 113.357, 53.237));
+    /// assert_eq!(color.hex(), "#ff0000");
+    /// ```
+    pub fn from_hct(hct: (f64, f64, f64)) -> Color {
+        let (r, g, b) = conversion::hct::hct2rgb(hct);
+        Color::new(r, g, b, 1.0)
+    }
+}
+
+/// Parse a hex color, a color function or a named color.
+///
+/// Hex colors need a `#` or `0x` prefix, see [`Color::from_hex`] for bare hex.
+///
+/// `lab()` and `lch()` are relative to the D50 white, like in CSS, for both the
+/// modern and the comma syntax. [`Color::lab`] and `mode("lab")` are relative to D65
+/// like chroma.js, so build colors from their values with [`Color::from_lab`] rather
+/// than by formatting a `lab()` string.
+///
+/// ```
+/// use chroma_rust::{Color, ParseColorError};
+///
+/// let color: Color = "hsl(120 100% 50% / 0.5)".parse().unwrap();
+/// assert_eq!(color.rgba(), (0, 255, 0, 0.5));
+///
+/// let error = "bread".parse::<Color>().unwrap_err();
+/// assert_eq!(error, ParseColorError::UnknownColor("bread".to_string()));
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
         let low_str = str.to_lowercase();
         let (r, g, b, a) = match &low_str {
-            str if str.starts_with("#") => conversion::hex::hex2rgb(str),
-            str if str.starts_with("rgba") => parser::parse_rgba_str(str),
-            str if str.starts_with("rgb") => parser::parse_rgb_str(str),
+            str if str.starts_with('#') || str.starts_with("0x") => conversion::hex::hex2rgb(str)?,
+            str if str.starts_with("rgba") => parser::parse_rgba_str(str)?,
+            str if str.starts_with("rgb") => parser::parse_rgb_str(str)?,
+            str if str.starts_with("lchuv") => {
+                let (l, c, h, alpha) = parser::parse_lchuv_str(str)?;
+                let luv = conversion::lch::lch2lab((l, c, h));
+                let (r, g, b) = conversion::luv::luv2rgb(luv);
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("lch") => {
+                let (l, c, h, alpha) = parser::parse_lch_str(str)?;
+                let lab = conversion::lch::lch2lab((l, c, h));
+                let (r, g, b) = Color::from_lab_with(lab, WhitePoint::D50, GamutMapping::Css).rgb();
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("oklab") => {
+                let (l, a, b, alpha) = parser::parse_oklab_str(str)?;
+                let (r, g, b) = Color::from_oklab((l, a, b), GamutMapping::Css).rgb();
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("oklch") => {
+                let (l, c, h, alpha) = parser::parse_oklch_str(str)?;
+                let (r, g, b) = Color::from_oklch((l, c, h), GamutMapping::Css).rgb();
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("luv") => {
+                let (l, u, v, alpha) = parser::parse_luv_str(str)?;
+                let (r, g, b) = conversion::luv::luv2rgb((l, u, v));
+                (r, g, b, alpha)
+            }
             str if str.starts_with("lab") => {
-                let (l, a, b) = parser::parse_lab_str(str);
-                conversion::lab::lab2rgb((l, a, b))
+                let (l, a, b, alpha) = parser::parse_lab_str(str)?;
+                let lab = (l, a, b);
+                let (r, g, b) = Color::from_lab_with(lab, WhitePoint::D50, GamutMapping::Css).rgb();
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("hsluv") => {
+                let (h, s, l, alpha) = parser::parse_hsluv_str(str)?;
+                let (r, g, b) = conversion::hsluv::hsluv2rgb((h, s, l));
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("hpluv") => {
+                let (h, s, l, alpha) = parser::parse_hpluv_str(str)?;
+                let (r, g, b) = conversion::hsluv::hpluv2rgb((h, s, l));
+                (r, g, b, alpha)
             }
             str if str.starts_with("hsl") => {
-                let (h, s, l) = parser::parse_hsl_str(str);
-                let (r, g, b) = conversion::hsl::hsl2rgb((h, s, l));
-                (r, g, b, 1.0)
+                let (h, s, l, alpha) = parser::parse_hsl_str(str)?;
+                let (r, g, b) = conversion::hsl::hsl2rgb((h.rem_euclid(360.), s, l));
+                (r, g, b, alpha)
             }
             str if str.starts_with("hsv") => {
-                let (h, s, v) = parser::parse_hsv_str(str);
-                let (r, g, b) = conversion::hsv::hsv2rgb((h, s, v));
-                (r, g, b, 1.0)
+                let (h, s, v, alpha) = parser::parse_hsv_str(str)?;
+                let (r, g, b) = conversion::hsv::hsv2rgb((h.rem_euclid(360.), s, v));
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("color(") => {
+                let (name, r, g, b, alpha) = parser::parse_color_str(str)?;
+                let space = RgbSpace::from_name(&name)
+                    .ok_or_else(|| ParseColorError::UnknownColorSpace(name.clone()))?;
+                let (r, g, b) = Color::from_rgb_space((r, g, b), &space).rgb();
+                (r, g, b, alpha)
+            }
+            str if str.starts_with("hwb") => {
+                let (h, w, b, alpha) = parser::parse_hwb_str(str)?;
+                let (r, g, b) = conversion::hwb::hwb2rgb((h, w, b));
+                (r, g, b, alpha)
             }
             str if str.starts_with("cmyk") => {
-                let (c, m, y, k) = parser::parse_cmyk_str(str);
+                let (c, m, y, k) = parser::parse_cmyk_str(str)?;
                 let (r, g, b) = conversion::cmyk::cmyk2rgb((c, m, y, k));
                 (r, g, b, 1.0)
             }
-            _ => {
-                let found = crate::W3CX11.get(str);
-                match found {
-                    Some(hex) => conversion::hex::hex2rgb(hex),
-                    None => panic!("Color not found"),
-                }
-            }
+            low_str => match named_hex(low_str) {
+                Some(hex) => conversion::hex::hex2rgb(hex)?,
+                None => return Err(ParseColorError::UnknownColor(str.to_string())),
+            },
         };
-        Color::new(r, g, b, a)
+        Ok(Color::new(r, g, b, a))
+    }
+}
+
+/// The hex string of a named color.
+pub(crate) fn named_hex(name: &str) -> Option<&'static str> {
+    let index = W3CX11.binary_search_by_key(&name, |(name, _)| name).ok()?;
+    Some(W3CX11[index].1)
+}
+
+/// Parse a color like [`FromStr`], panicking when the string isn't a color.
+impl From<&str> for Color {
+    fn from(str: &str) -> Self {
+        match str.parse() {
+            Ok(color) => color,
+            Err(err) => panic!("{}", err),
+        }
     }
 }
 
@@ -61,18 +270,19 @@ impl From<u32> for Color {
     }
 }
 
-impl Iterator for Color {
-    type Item = f64;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let (r, g, b, a) = self.rgba();
-        vec![r as f64, g as f64, b as f64, a].into_iter().next()
+impl From<Xyz> for Color {
+    fn from(xyz: Xyz) -> Self {
+        let (r, g, b) = conversion::xyz::xyz2rgb(xyz.into());
+        Color {
+            rgba: (r, g, b, 1.0),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
     fn test_color_from_hex_str() {
@@ -83,6 +293,103 @@ mod tests {
         assert_eq!(hex_color.hex(), "#abcdef");
     }
 
+    #[test]
+    fn test_color_from_str_invalid() {
+        let err = |s: &str| s.parse::<Color>().unwrap_err();
+        assert_eq!(err("bread"), ParseColorError::UnknownColor("bread".into()));
+        assert_eq!(err("#abcde"), ParseColorError::InvalidHex("#abcde".into()));
+        assert_eq!(
+            err("rgb(1, 2)"),
+            ParseColorError::WrongChannelCount("rgb(1, 2)".into())
+        );
+        assert_eq!(
+            err("hsl(a, 1, 1)"),
+            ParseColorError::InvalidNumber("a".into())
+        );
+        assert_eq!(
+            err("color(p3 1 0 0)"),
+            ParseColorError::UnknownColorSpace("p3".into())
+        );
+        assert_eq!(
+            err("hsl(1e400,1,1)"),
+            ParseColorError::InvalidNumber("1e400".into())
+        );
+        assert_eq!(
+            err("hsv(inf, 1, 1)"),
+            ParseColorError::InvalidNumber("inf".into())
+        );
+        assert_eq!(
+            err("rgb(1 2 3 4 / 0.5)"),
+            ParseColorError::WrongChannelCount("rgb(1 2 3 4 / 0.5)".into())
+        );
+    }
+
+    #[test]
+    fn test_color_from_str_hue_and_alpha() {
+        // hues outside of 0..360 wrap around
+        assert_eq!(
+            Color::from("hsl(400, 50%, 50%)"),
+            Color::from("hsl(40, 50%, 50%)")
+        );
+        assert_eq!(
+            Color::from("hsl(-30 100% 50%)"),
+            Color::from("hsl(330 100% 50%)")
+        );
+        assert_eq!(Color::from("hsv(720, 1, 1)").hex(), "#ff0000");
+        assert_eq!(Color::from("hsl(-1e-15 100% 50%)").hex(), "#ff0000");
+
+        // the alpha is clamped, and can be a fourth channel
+        assert_eq!(Color::from("hsl(0 100% 50% / 2)").alpha(), 1.);
+        assert_eq!(Color::from("color(srgb 1 0 0 / -1)").alpha(), 0.);
+        assert_eq!(Color::from("rgb(255, 0, 0, 0.5)").alpha(), 0.5);
+        assert_eq!(Color::from("hsv(0 1 1 / 0.5)").rgba(), (255, 0, 0, 0.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Color not found: `bread`")]
+    fn test_color_from_invalid() {
+        let _ = Color::from("bread");
+    }
+
+    #[test]
+    fn test_color_from_hex_prefix() {
+        assert_eq!(Color::from("#f008").rgba, (255, 0, 0, 0x88 as f64 / 255.));
+        assert_eq!(Color::from("0xFF0000").rgba, (255, 0, 0, 1.0));
+        assert_eq!(
+            Color::from_hex("ff000080").unwrap().rgba,
+            (255, 0, 0, 0x80 as f64 / 255.)
+        );
+        assert_eq!(Color::from_hex("0xf00").unwrap().hex(), "#ff0000");
+        assert_eq!(
+            Color::from_hex("facad"),
+            Err(ParseColorError::InvalidHex("facad".into()))
+        );
+        let short = crate::HexOptions {
+            short: true,
+            ..Default::default()
+        };
+        assert_eq!(Color::from("#f0c8").hex_with(&short), "#f0c8");
+        for word in [
+            "add", "bad", "cafe", "facade", "decade", "deadbeef", "ff0000",
+        ] {
+            assert!(word.parse::<Color>().is_err(), "{}", word);
+        }
+        assert_eq!(Color::from("#cafe").hex(), "#ccaaffee");
+        assert_eq!(Color::from("red").rgba, (255, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn test_color_from_str_case() {
+        assert_eq!("RED".parse(), Ok(Color::new(255, 0, 0, 1.)));
+        assert_eq!(Color::from("MediumSpringGreen").hex(), "#00fa9a");
+        assert_eq!(Color::from_hex("ABCDEF").unwrap().hex(), "#abcdef");
+        assert_eq!(Color::from("#ABCDEF").hex(), "#abcdef");
+        assert_eq!(
+            "Bread".parse::<Color>(),
+            Err(ParseColorError::UnknownColor("Bread".into()))
+        );
+    }
+
     #[test]
     fn test_color_from_rgb_str() {
         let rgb_color = Color::from("rgb(255, 255, 255)");
@@ -111,6 +418,55 @@ mod tests {
                 rgba: (255, 255, 255, 1.)
             }
         );
+
+        // out of gamut, mapped keeping the OKLCh hue where clipping the channels shifts it
+        for lab in [(30., 0., -120.), (30., -120., -60.), (50., -120., -120.)] {
+            let color = Color::from(format!("lab({} {} {})", lab.0, lab.1, lab.2).as_str());
+            let xyz = conversion::lab::lab2xyz(lab, WhitePoint::D50);
+            let xyz = Adaptation::Bradford.adapt(xyz, WhitePoint::D50, WhitePoint::D65);
+            let (_, _, hue) = conversion::lch::lab2lch(conversion::oklab::xyz2oklab(xyz));
+            let hue_diff = |c: Color| ((c.oklch().2 - hue + 180.).rem_euclid(360.) - 180.).abs();
+            assert!(
+                hue_diff(color) < 2.,
+                "hue {} instead of {}",
+                color.oklch().2,
+                hue
+            );
+            let clipped = Color::from_lab_with(lab, WhitePoint::D50, GamutMapping::Clip);
+            assert!(hue_diff(clipped) > 30.);
+        }
+    }
+
+    #[test]
+    fn test_color_from_lab_str_white_point() {
+        let color = Color::from("#336699");
+        let (l, a, b) = color.lab_with(WhitePoint::D50);
+        assert_eq!(
+            Color::from(format!("lab({}, {}, {})", l, a, b).as_str()),
+            color
+        );
+        let (l, c, h) = conversion::lch::lab2lch((l, a, b));
+        assert_eq!(
+            Color::from(format!("lch({} {} {})", l, c, h).as_str()),
+            color
+        );
+
+        // the D65 values of `lab()` are another color
+        let (l, a, b) = color.lab();
+        assert_eq!(Color::from_lab((l, a, b), GamutMapping::Clip), color);
+        assert_ne!(
+            Color::from(format!("lab({}, {}, {})", l, a, b).as_str()),
+            color
+        );
+    }
+
+    #[test]
+    fn test_named_hex() {
+        // the binary search needs the table sorted by name
+        for (name, hex) in W3CX11 {
+            assert_eq!(named_hex(name), Some(hex));
+        }
+        assert_eq!(named_hex("bread"), None);
     }
 
     #[test]
@@ -178,6 +534,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_color_from_hwb_str() {
+        let hwb_color = Color::from("hwb(90, 40%, 20%)");
+        assert_eq!(hwb_color.hex(), "#99cc66");
+
+        let hwb_color = Color::from("hwb(240deg 0% 0% / 0.5)");
+        assert_eq!(hwb_color.rgba(), (0, 0, 255, 0.5));
+
+        let hwb_color = Color::from("hwb(0 60% 60%)");
+        assert_eq!(hwb_color.hex(), "#808080");
+
+        let hwb_color = Color::from("hwb(-1e-15 0% 0%)");
+        assert_eq!(hwb_color.hex(), "#ff0000");
+    }
+
+    #[test]
+    fn test_color_from_luv_str() {
+        let luv_color = Color::from("luv(53.24, 175.01, 37.76)");
+        assert_eq!(luv_color.hex(), "#ff0000");
+
+        let lchuv_color = Color::from("lchuv(53.24 179.04 12.18 / 0.5)");
+        assert_eq!(lchuv_color.rgba(), (255, 0, 0, 0.5));
+    }
+
+    #[test]
+    fn test_color_from_hsluv_str() {
+        let hsluv_color = Color::from("hsluv(265.874, 100, 32.301)");
+        assert_eq!(hsluv_color.hex(), "#0000ff");
+
+        let hsluv_color = Color::from("hsluv(0, 0%, 100%)");
+        assert_eq!(hsluv_color.hex(), "#ffffff");
+
+        let hpluv_color = Color::from("hpluv(0, 0, 53.389)");
+        assert_eq!(hpluv_color.hex(), "#808080");
+    }
+
     #[test]
     fn test_color_from_num() {
         let num_color = Color::from(0xff0000);
@@ -208,6 +600,51 @@ mod tests {
         assert_eq!(num_color.hex(), "#abcdef");
     }
 
+    #[test]
+    fn test_color_from_xyz() {
+        let color = Color::from(Xyz::new(0.4125, 0.2127, 0.0193));
+        assert_eq!(color.hex(), "#ff0000");
+
+        let color = Color::from(Xyz::from_xyy(0.3127, 0.3290, 1.));
+        assert_eq!(color.hex(), "#ffffff");
+    }
+
+    #[test]
+    fn test_color_from_color_fn_str() {
+        let color = Color::from("color(display-p3 0.9175 0.2003 0.1386)");
+        assert_eq!(color.hex(), "#ff0000");
+
+        let color = Color::from("color(srgb 1 1 1 / 0.5)");
+        assert_eq!(color.rgba(), (255, 255, 255, 0.5));
+
+        // out of sRGB gamut, clipped
+        let color = Color::from("color(rec2020 0 1 0)");
+        assert_eq!(color.rgb().1, 255);
+    }
+
+    #[test]
+    fn test_color_from_oklch_str() {
+        let color = Color::from("oklch(0.628 0.2577 29.23)");
+        assert_eq!(color.hex(), "#ff0000");
+
+        let color = Color::from("oklch(62.8% 0.2577 29.23deg / 0.5)");
+        assert_eq!(color.rgba(), (255, 0, 0, 0.5));
+
+        let color = Color::from("oklab(0.628, 0.2249, 0.1258)");
+        assert_eq!(color.hex(), "#ff0000");
+
+        let color = Color::from("lch(54.29, 106.84, 40.85)");
+        assert_eq!(color.hex(), "#ff0000");
+    }
+
+    #[test]
+    fn test_color_from_out_of_gamut() {
+        let mapped = Color::from_oklch((0.7, 0.4, 150.), GamutMapping::Css);
+        let clipped = Color::from_oklch((0.7, 0.4, 150.), GamutMapping::Clip);
+        assert_ne!(mapped, clipped);
+        assert!((mapped.oklch().2 - 150.).abs() < (clipped.oklch().2 - 150.).abs());
+    }
+
     #[test]
     fn test_color_from_cmyk_str() {
         let cmyk_color = Color::from("cmyk(0, 100%, 100%, 0)");
@@ -0,0 +1,186 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::{delta_e_with, Color, DeltaE};
+
+impl Color {
+    /// The canonical form of the color compared and hashed by `Eq`, `Hash` and `Ord`,
+    /// its alpha is clamped to `0..1` and quantized to 16 bits.
+    fn canonical(&self) -> (u8, u8, u8, u16) {
+        let (r, g, b, a) = self.rgba;
+        (r, g, b, (a.clamp(0., 1.) * 65535.).round() as u16)
+    }
+}
+
+/// Colors are equal when their canonical forms are, so they can be
+/// deduplicated or used as `HashMap` keys.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use chroma_rust::Color;
+///
+/// let colors: HashSet<Color> = ["red", "#f00", "rgb(255 0 0)", "blue"]
+///     .into_iter()
+///     .map(Color::from)
+///     .collect();
+/// assert_eq!(colors.len(), 2);
+/// ```
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Color {}
+
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+/// Colors are ordered by red, green, blue then alpha, see [`Color::cmp_by`]
+/// for other orders.
+impl PartialOrd for Color {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Color {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical().cmp(&other.canonical())
+    }
+}
+
+/// The key to sort colors by with [`Color::cmp_by`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SortKey {
+    /// Red, green, blue then alpha, like `Ord`
+    #[default]
+    Rgb,
+    /// The OKLCh hue
+    Hue,
+    /// The OKLab lightness
+    Lightness,
+    /// The OKLCh chroma
+    Chroma,
+    Alpha,
+}
+
+impl Color {
+    /// Compare two colors by the given key, e.g. to sort them with `sort_by`.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, SortKey};
+    ///
+    /// let mut colors: Vec<Color> = ["white", "navy", "gray"].into_iter().map(Color::from).collect();
+    /// colors.sort_by(|a, b| a.cmp_by(b, SortKey::Lightness));
+    /// assert_eq!(colors[0].hex(), "#000080");
+    /// ```
+    pub fn cmp_by(&self, other: &Color, key: SortKey) -> Ordering {
+        let value: fn(&Color) -> f64 = match key {
+            SortKey::Rgb => return self.cmp(other),
+            SortKey::Hue => |color| color.oklch().2,
+            SortKey::Lightness => |color| color.oklab().0,
+            SortKey::Chroma => |color| color.oklch().1,
+            SortKey::Alpha => Color::alpha,
+        };
+        value(self).total_cmp(&value(other))
+    }
+
+    /// Whether two colors look the same, their Delta E being at most `tolerance`.
+    ///
+    /// The alphas must also be within `tolerance / 100` of each other, as the
+    /// Lab lightness is `0..100`.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, DeltaE};
+    ///
+    /// let c1 = Color::from("#ededee");
+    /// let c2 = Color::from("#edeeed");
+    /// assert!(c1.approx_eq(&c2, 2., DeltaE::Ciede2000));
+    /// assert!(!c1.approx_eq(&c2, 0.5, DeltaE::Ciede2000));
+    /// ```
+    pub fn approx_eq(&self, other: &Color, tolerance: f64, metric: DeltaE) -> bool {
+        (self.alpha() - other.alpha()).abs() * 100. <= tolerance
+            && delta_e_with(self, other, metric) <= tolerance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::String, vec, vec::Vec};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_eq_hash() {
+        assert_eq!(Color::new(1, 2, 3, 0.5), Color::new(1, 2, 3, 0.500001));
+        assert_ne!(Color::new(1, 2, 3, 0.5), Color::new(1, 2, 3, 0.51));
+        assert_eq!(Color::new(1, 2, 3, 1.5), Color::new(1, 2, 3, 1.));
+        let nan = Color::new(1, 2, 3, f64::NAN);
+        assert_eq!(nan, nan);
+
+        let mut counts = HashMap::new();
+        for name in ["red", "#ff0000", "blue", "#0000ffff"] {
+            *counts.entry(Color::from(name)).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&Color::from("red")], 2);
+        assert_eq!(counts[&Color::from("blue")], 2);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut colors = vec![
+            Color::new(0, 0, 255, 1.),
+            Color::new(255, 0, 0, 1.),
+            Color::new(0, 0, 255, 0.5),
+        ];
+        colors.sort();
+        assert_eq!(
+            colors,
+            vec![
+                Color::new(0, 0, 255, 0.5),
+                Color::new(0, 0, 255, 1.),
+                Color::new(255, 0, 0, 1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cmp_by() {
+        let mut colors: Vec<Color> = ["blue", "red", "lime", "yellow"]
+            .into_iter()
+            .map(Color::from)
+            .collect();
+        colors.sort_by(|a, b| a.cmp_by(b, SortKey::Hue));
+        let hex: Vec<String> = colors.iter().map(Color::hex).collect();
+        assert_eq!(hex, vec!["#ff0000", "#ffff00", "#00ff00", "#0000ff"]);
+
+        colors.sort_by(|a, b| a.cmp_by(b, SortKey::Lightness));
+        assert_eq!(colors[0].hex(), "#0000ff");
+        assert_eq!(colors[3].hex(), "#ffff00");
+
+        let gray = Color::from("gray");
+        assert_eq!(gray.cmp_by(&colors[0], SortKey::Chroma), Ordering::Less);
+        assert_eq!(
+            gray.cmp_by(&Color::new(0, 0, 0, 0.5), SortKey::Alpha),
+            Ordering::Greater
+        );
+        assert_eq!(gray.cmp_by(&gray, SortKey::Rgb), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let c = Color::from("#336699");
+        assert!(c.approx_eq(&Color::from("#336698"), 1., DeltaE::Cie76));
+        assert!(!c.approx_eq(&Color::from("#3366cc"), 1., DeltaE::Cie94));
+
+        let mut transparent = c;
+        transparent.set_alpha(0.5);
+        assert!(!c.approx_eq(&transparent, 1., DeltaE::Ciede2000));
+    }
+}
@@ -0,0 +1,310 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use crate::utils::{conversion, round};
+use crate::{Color, RgbSpace, WhitePoint};
+
+/// The CSS function used to serialize a color.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CssNotation {
+    /// `rgb()`, the alpha is only written when it's less than 1
+    Rgb,
+    /// `rgba()`, the alpha is always written
+    Rgba,
+    Hsl,
+    Hwb,
+    /// `lab()`, relative to the D50 white like CSS, see [`Color::lab_with`]
+    Lab,
+    /// `lch()`, relative to the D50 white like CSS
+    Lch,
+    Oklab,
+    Oklch,
+    /// `color()` in the given RGB color space
+    Color(RgbSpace),
+}
+
+/// How to serialize a color as a CSS string, see [`Color::css`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CssFormat {
+    pub notation: CssNotation,
+    /// Separate the channels with commas, like CSS Color 3, instead of spaces
+    /// and a slash before the alpha. Only `rgb()`, `rgba()` and `hsl()` have
+    /// this legacy syntax, the other notations ignore it.
+    pub legacy: bool,
+    /// Number of decimals of the channels
+    pub precision: u32,
+}
+
+impl CssFormat {
+    pub fn new(notation: CssNotation) -> CssFormat {
+        CssFormat {
+            notation,
+            legacy: false,
+            precision: 4,
+        }
+    }
+}
+
+impl Default for CssFormat {
+    fn default() -> Self {
+        CssFormat::new(CssNotation::Rgb)
+    }
+}
+
+impl From<CssNotation> for CssFormat {
+    fn from(notation: CssNotation) -> Self {
+        CssFormat::new(notation)
+    }
+}
+
+impl Color {
+    /// Return the color as a CSS string.
+    ///
+    /// The alpha is written when it's less than 1, or always with [`CssNotation::Rgba`].
+    /// Like the channels, it's rounded to the precision.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, CssFormat, CssNotation};
+    ///
+    /// let color = Color::from("rgba(255, 128, 0, 0.5)");
+    /// assert_eq!(color.css(CssNotation::Rgb), "rgb(255 128 0 / 0.5)");
+    /// assert_eq!(color.css(CssNotation::Hwb), "hwb(30.1176 0% 0% / 0.5)");
+    ///
+    /// let format = CssFormat {
+    ///     legacy: true,
+    ///     precision: 1,
+    ///     ..CssFormat::new(CssNotation::Hsl)
+    /// };
+    /// assert_eq!(color.css(format), "hsla(30.1, 100%, 50%, 0.5)");
+    /// ```
+    pub fn css(&self, format: impl Into<CssFormat>) -> String {
+        let format: CssFormat = format.into();
+        let p = format.precision;
+        let number = |v: f64| format!("{}", round(v, p) + 0.);
+        let percent = |v: f64| format!("{}%", round(v * 100., p) + 0.);
+        let (r, g, b) = self.rgb();
+
+        let (name, channels) = match format.notation {
+            CssNotation::Rgb => ("rgb", [r, g, b].map(|v| v.to_string())),
+            CssNotation::Rgba => ("rgba", [r, g, b].map(|v| v.to_string())),
+            CssNotation::Hsl => {
+                let (h, s, l) = self.hsl();
+                ("hsl", [number(h), percent(s), percent(l)])
+            }
+            CssNotation::Hwb => {
+                let (h, w, b) = self.hwb();
+                ("hwb", [number(h), percent(w), percent(b)])
+            }
+            CssNotation::Lab => {
+                let (l, a, b) = self.lab_with(WhitePoint::D50);
+                ("lab", [l, a, b].map(number))
+            }
+            CssNotation::Lch => {
+                let (l, c, h) = conversion::lch::lab2lch(self.lab_with(WhitePoint::D50));
+                ("lch", [l, c, h].map(number))
+            }
+            CssNotation::Oklab => {
+                let (l, a, b) = self.oklab();
+                ("oklab", [l, a, b].map(number))
+            }
+            CssNotation::Oklch => {
+                let (l, c, h) = self.oklch();
+                ("oklch", [l, c, h].map(number))
+            }
+            CssNotation::Color(space) => {
+                let (r, g, b) = self.rgb_space(&space);
+                let channels = [r, g, b].map(number).join(" ");
+                return with_alpha(format!("color({} {}", space.name, channels), self, p);
+            }
+        };
+
+        let alpha = round(self.alpha(), p);
+        let always = format.notation == CssNotation::Rgba;
+        if format.legacy && matches!(name, "rgb" | "rgba" | "hsl") {
+            let name = match (name, alpha < 1. || always) {
+                ("rgb", true) => "rgba",
+                ("hsl", true) => "hsla",
+                _ => name,
+            };
+            if alpha < 1. || always {
+                format!("{}({}, {})", name, channels.join(", "), alpha)
+            } else {
+                format!("{}({})", name, channels.join(", "))
+            }
+        } else if always {
+            format!("{}({} / {})", name, channels.join(" "), alpha)
+        } else {
+            with_alpha(format!("{}({}", name, channels.join(" ")), self, p)
+        }
+    }
+}
+
+/// Close the CSS function, with the alpha rounded to `precision` decimals when it's less than 1.
+fn with_alpha(start: String, color: &Color, precision: u32) -> String {
+    let alpha = round(color.alpha(), precision);
+    if alpha < 1. {
+        format!("{} / {})", start, alpha)
+    } else {
+        format!("{})", start)
+    }
+}
+
+/// Write the color as a CSS `rgb()` string, which `Color::from` parses back
+/// with the alpha rounded to 4 decimals.
+///
+/// ```
+/// use chroma_rust::Color;
+///
+/// let color = Color::from("rgba(255, 0, 0, 0.5)");
+/// assert_eq!(color.to_string(), "rgb(255 0 0 / 0.5)");
+/// assert_eq!(Color::from(color.to_string().as_str()), color);
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.css(CssNotation::Rgb))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_rgb() {
+        let color = Color::from("#ff8000");
+        assert_eq!(color.css(CssNotation::Rgb), "rgb(255 128 0)");
+        assert_eq!(color.css(CssNotation::Rgba), "rgba(255 128 0 / 1)");
+
+        let legacy = |notation| CssFormat {
+            legacy: true,
+            ..CssFormat::new(notation)
+        };
+        assert_eq!(color.css(legacy(CssNotation::Rgb)), "rgb(255, 128, 0)");
+        assert_eq!(color.css(legacy(CssNotation::Rgba)), "rgba(255, 128, 0, 1)");
+
+        let mut color = color;
+        color.set_alpha(0.25);
+        assert_eq!(color.css(CssNotation::Rgb), "rgb(255 128 0 / 0.25)");
+        assert_eq!(
+            color.css(legacy(CssNotation::Rgb)),
+            "rgba(255, 128, 0, 0.25)"
+        );
+    }
+
+    #[test]
+    fn test_css_notations() {
+        let color = Color::from("#336699");
+        let format = |notation| CssFormat {
+            precision: 2,
+            ..CssFormat::new(notation)
+        };
+        assert_eq!(color.css(format(CssNotation::Hsl)), "hsl(210 50% 40%)");
+        assert_eq!(color.css(format(CssNotation::Hwb)), "hwb(210 20% 40%)");
+        assert_eq!(
+            color.css(format(CssNotation::Lab)),
+            "lab(41.52 -4.57 -33.49)"
+        );
+        assert_eq!(
+            color.css(format(CssNotation::Oklab)),
+            "oklab(0.5 -0.03 -0.09)"
+        );
+        assert_eq!(
+            color.css(CssFormat {
+                legacy: true,
+                ..format(CssNotation::Hsl)
+            }),
+            "hsl(210, 50%, 40%)"
+        );
+        // the other notations have no legacy syntax
+        let legacy = |notation| CssFormat {
+            legacy: true,
+            ..format(notation)
+        };
+        assert_eq!(
+            color.css(legacy(CssNotation::Lab)),
+            "lab(41.52 -4.57 -33.49)"
+        );
+        assert_eq!(color.css(legacy(CssNotation::Hwb)), "hwb(210 20% 40%)");
+        assert_eq!(
+            color.css(CssNotation::Color(RgbSpace::SRGB)),
+            "color(srgb 0.2 0.4 0.6)"
+        );
+    }
+
+    #[test]
+    fn test_css_round_trip() {
+        let mut color = Color::from("#336699");
+        color.set_alpha(0.5);
+        let notations = [
+            CssNotation::Rgb,
+            CssNotation::Rgba,
+            CssNotation::Hsl,
+            CssNotation::Hwb,
+            CssNotation::Lab,
+            CssNotation::Lch,
+            CssNotation::Oklab,
+            CssNotation::Oklch,
+            CssNotation::Color(RgbSpace::DISPLAY_P3),
+        ];
+        for notation in notations {
+            for legacy in [false, true] {
+                let format = CssFormat {
+                    legacy,
+                    ..CssFormat::new(notation)
+                };
+                let css = color.css(format);
+                assert_eq!(Color::from(css.as_str()), color, "{}", css);
+                let is_legacy = matches!(
+                    notation,
+                    CssNotation::Rgb | CssNotation::Rgba | CssNotation::Hsl
+                );
+                assert_eq!(css.contains(','), legacy && is_legacy, "{}", css);
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let color = Color::from("white");
+        assert_eq!(format!("{}", color), "rgb(255 255 255)");
+        assert_eq!(Color::from(color.to_string().as_str()), color);
+    }
+
+    #[test]
+    fn test_css_alpha_precision() {
+        let color = Color::from([255, 0, 0, 128]);
+        assert_eq!(color.to_string(), "rgb(255 0 0 / 0.502)");
+        let format = |notation, legacy| CssFormat {
+            legacy,
+            precision: 2,
+            ..CssFormat::new(notation)
+        };
+        assert_eq!(
+            color.css(format(CssNotation::Hsl, false)),
+            "hsl(0 100% 50% / 0.5)"
+        );
+        assert_eq!(
+            color.css(format(CssNotation::Hsl, true)),
+            "hsla(0, 100%, 50%, 0.5)"
+        );
+        assert_eq!(
+            color.css(format(CssNotation::Rgba, true)),
+            "rgba(255, 0, 0, 0.5)"
+        );
+        assert_eq!(
+            color.css(format(CssNotation::Rgba, false)),
+            "rgba(255 0 0 / 0.5)"
+        );
+        assert_eq!(
+            color.css(format(CssNotation::Color(RgbSpace::SRGB), false)),
+            "color(srgb 1 0 0 / 0.5)"
+        );
+
+        // an alpha that rounds to 1 isn't written
+        let color = Color::new(255, 0, 0, 0.99999);
+        assert_eq!(color.to_string(), "rgb(255 0 0)");
+    }
+}
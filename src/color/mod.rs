@@ -1,6 +1,19 @@
 pub mod alpha;
+pub mod channels;
+#[allow(clippy::module_inception)]
 pub mod color;
+pub mod compare;
+pub mod css;
 pub mod mode;
+pub mod name;
 pub mod spaces;
+pub mod token;
+pub mod vector;
 
+pub use channels::Channels;
 pub use color::Color;
+pub use compare::SortKey;
+pub use css::{CssFormat, CssNotation};
+pub use name::NameDictionary;
+pub use token::{TokenColor, TokenColorSpace};
+pub use vector::{Lab, LinearRgb};
@@ -1,11 +1,26 @@
-use crate::Color;
+use alloc::{format, vec, vec::Vec};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::{utils::conversion, Cam16, Color, GamutMapping, RgbSpace, ViewingConditions, Xyz};
 
 impl Color {
     /// Get color with mode
     ///
-    /// mode can be `rgb`, `rgba`, `lab`, `hsl`, `hsv`, `cmyk`
+    /// mode can be `rgb`, `rgba`, `lab`, `lch`, `oklab`, `oklch`, `hsl`, `hsv`, `hwb`, `hsi`,
+    /// `hcg`, `luv`, `lchuv`, `hsluv`, `hpluv`, `cam16` (J, C, h), `cam16-ucs`, `hct`, `cmyk`, `xyz`, `xyy`, `yuv`, `yiq`, a YCbCr variant such as `ycbcr` or
+    /// `ycbcr-bt709-limited` (see [`Color::ycbcr`]), or the name of an RGB color space,
+    /// e.g. `display-p3` (see [`RgbSpace`])
     pub fn mode(&self, mode: &str) -> Vec<f64> {
-        match mode {
+        match self.try_mode(mode) {
+            Some(channels) => channels,
+            None => todo!(),
+        }
+    }
+
+    /// Like [`Color::mode`], but returns `None` for an unknown mode.
+    pub(crate) fn try_mode(&self, mode: &str) -> Option<Vec<f64>> {
+        let channels = match mode {
             "rgb" => {
                 let (r, g, b) = self.rgb();
                 vec![r as f64, g as f64, b as f64]
@@ -18,6 +33,18 @@ impl Color {
                 let (l, a, b) = self.lab();
                 vec![l, a, b]
             }
+            "lch" => {
+                let (l, c, h) = self.lch();
+                vec![l, c, h]
+            }
+            "oklab" => {
+                let (l, a, b) = self.oklab();
+                vec![l, a, b]
+            }
+            "oklch" => {
+                let (l, c, h) = self.oklch();
+                vec![l, c, h]
+            }
             "hsl" => {
                 let (h, s, l) = self.hsl();
                 vec![h, s, l]
@@ -26,14 +53,82 @@ impl Color {
                 let (h, s, v) = self.hsv();
                 vec![h, s, v]
             }
+            "hwb" => {
+                let (h, w, b) = self.hwb();
+                vec![h, w, b]
+            }
+            "hsi" => {
+                let (h, s, i) = self.hsi();
+                vec![h, s, i]
+            }
+            "hcg" => {
+                let (h, c, g) = self.hcg();
+                vec![h, c, g]
+            }
+            "luv" => {
+                let (l, u, v) = self.luv();
+                vec![l, u, v]
+            }
+            "lchuv" => {
+                let (l, c, h) = self.lchuv();
+                vec![l, c, h]
+            }
+            "hsluv" => {
+                let (h, s, l) = self.hsluv();
+                vec![h, s, l]
+            }
+            "hpluv" => {
+                let (h, s, l) = self.hpluv();
+                vec![h, s, l]
+            }
+            "cam16" => {
+                let cam = self.cam16();
+                vec![cam.j, cam.c, cam.h]
+            }
+            "cam16-ucs" => {
+                let (j, a, b) = self.cam16_ucs();
+                vec![j, a, b]
+            }
+            "hct" => {
+                let (h, c, t) = self.hct();
+                vec![h, c, t]
+            }
             "cmyk" => {
                 let (c, m, y, k) = self.cmyk();
                 vec![c, m, y, k]
             }
-            _ => todo!(),
-        }
+            "xyz" => {
+                let (x, y, z) = self.xyz();
+                vec![x, y, z]
+            }
+            "xyy" => {
+                let (x, y, big_y) = self.xyy();
+                vec![x, y, big_y]
+            }
+            "yuv" => {
+                let (y, u, v) = self.yuv();
+                vec![y, u, v]
+            }
+            "yiq" => {
+                let (y, i, q) = self.yiq();
+                vec![y, i, q]
+            }
+            _ => {
+                if let Some((standard, range)) = conversion::ycbcr::ycbcr_mode(mode) {
+                    let (y, cb, cr) = self.ycbcr(standard, range);
+                    return Some(vec![y, cb, cr]);
+                }
+                let (r, g, b) = self.rgb_space(&RgbSpace::from_name(mode)?);
+                vec![r, g, b]
+            }
+        };
+        Some(channels)
     }
 
+    /// Construct a color from its channels in the given mode, the inverse of [`Color::mode`].
+    ///
+    /// Channels outside of the sRGB gamut are clipped, use e.g. [`Color::from_oklch`]
+    /// to map them instead.
     pub fn vec_mode2color(vec_f64: Vec<f64>, mode: &str) -> Color {
         let len = vec_f64.len();
         match mode {
@@ -69,11 +164,34 @@ impl Color {
                         mode, len
                     )
                 }
-                let l = vec_f64[0];
-                let a = vec_f64[1];
-                let b = vec_f64[2];
-                let color_str = format!("lab({}, {}, {})", l, a, b);
-                Color::from(color_str.as_str())
+                Color::from_lab((vec_f64[0], vec_f64[1], vec_f64[2]), GamutMapping::Clip)
+            }
+            "lch" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from_lch((vec_f64[0], vec_f64[1], vec_f64[2]), GamutMapping::Clip)
+            }
+            "oklab" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from_oklab((vec_f64[0], vec_f64[1], vec_f64[2]), GamutMapping::Clip)
+            }
+            "oklch" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from_oklch((vec_f64[0], vec_f64[1], vec_f64[2]), GamutMapping::Clip)
             }
             "hsl" => {
                 if len != 3 {
@@ -101,6 +219,107 @@ impl Color {
                 let color_str = format!("hsv({}, {}, {})", h, s, v);
                 Color::from(color_str.as_str())
             }
+            "hwb" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::hwb::hwb2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "hsi" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::hsi::hsi2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "hcg" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::hcg::hcg2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "luv" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::luv::luv2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "lchuv" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::luv::luv2rgb(conversion::lch::lch2lab((
+                    vec_f64[0], vec_f64[1], vec_f64[2],
+                )));
+                Color::new(r, g, b, 1.0)
+            }
+            "hsluv" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::hsluv::hsluv2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "hpluv" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::hsluv::hpluv2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "cam16" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let vc = ViewingConditions::default();
+                let cam = Cam16::from_jch(vec_f64[0], vec_f64[1], vec_f64[2], &vc);
+                Color::from_cam16(&cam, &vc)
+            }
+            "cam16-ucs" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from_cam16_ucs((vec_f64[0], vec_f64[1], vec_f64[2]))
+            }
+            "hct" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from_hct((vec_f64[0], vec_f64[1], vec_f64[2]))
+            }
             "cmyk" => {
                 if len != 4 {
                     panic!(
@@ -115,7 +334,65 @@ impl Color {
                 let color_str = format!("cmyk({}, {}, {}, {})", c, m, y, k);
                 Color::from(color_str.as_str())
             }
-            _ => todo!(),
+            "xyz" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from(Xyz::new(vec_f64[0], vec_f64[1], vec_f64[2]))
+            }
+            "xyy" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                Color::from(Xyz::from_xyy(vec_f64[0], vec_f64[1], vec_f64[2]))
+            }
+            "yuv" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::yuv::yuv2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            "yiq" => {
+                if len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                let (r, g, b) = conversion::yiq::yiq2rgb((vec_f64[0], vec_f64[1], vec_f64[2]));
+                Color::new(r, g, b, 1.0)
+            }
+            _ => {
+                let ycbcr = conversion::ycbcr::ycbcr_mode(mode);
+                let space = RgbSpace::from_name(mode);
+                if (ycbcr.is_some() || space.is_some()) && len != 3 {
+                    panic!(
+                        "The {} mode must got a vec which len is 3, but got {}",
+                        mode, len
+                    )
+                }
+                if let Some((standard, range)) = ycbcr {
+                    let channels = (vec_f64[0], vec_f64[1], vec_f64[2]);
+                    let (r, g, b) = conversion::ycbcr::ycbcr2rgb(channels, standard, range);
+                    return Color::new(r, g, b, 1.0);
+                }
+                match space {
+                    Some(space) => {
+                        Color::from_rgb_space((vec_f64[0], vec_f64[1], vec_f64[2]), &space)
+                    }
+                    None => todo!(),
+                }
+            }
         }
     }
 }
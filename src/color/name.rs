@@ -0,0 +1,211 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::data::basic::{BASIC_BY_HEX, BASIC_LAB};
+use crate::data::w3cx11::{W3CX11_BY_HEX, W3CX11_LAB};
+use crate::utils::distance::ciede2000;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::Color;
+
+/// A dictionary of color names, see [`Color::names_in`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum NameDictionary {
+    /// The [X11 color names](http://www.w3.org/TR/css3-color/#svg-color) of CSS,
+    /// which are also parsed
+    #[default]
+    W3cx11,
+    /// The 16 [basic colors](https://www.w3.org/TR/css-color-3/#html4) of HTML 4 and CSS 1
+    Basic,
+}
+
+impl NameDictionary {
+    /// The names keyed by RGB number, sorted by number then name
+    fn by_hex(self) -> &'static [(u32, &'static str)] {
+        match self {
+            NameDictionary::W3cx11 => &W3CX11_BY_HEX,
+            NameDictionary::Basic => &BASIC_BY_HEX,
+        }
+    }
+
+    /// The colors of the dictionary in CIE Lab, sorted by name
+    fn labs(self) -> &'static [(&'static str, (f64, f64, f64))] {
+        match self {
+            NameDictionary::W3cx11 => &W3CX11_LAB,
+            NameDictionary::Basic => &BASIC_LAB,
+        }
+    }
+}
+
+impl Color {
+    /// Returns the named color.
+    ///
+    /// Falls back to hexadecimal RGB string, if the color isn't present.
+    /// The first alias in alphabetical order is returned, e.g. `aqua` rather than `cyan`.
+    ///
+    /// Named color from [*w3cx11*](http://www.w3.org/TR/css3-color/#svg-color)
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// let color = Color::from("#ff0");
+    /// assert_eq!(color.name(), "yellow");
+    ///
+    /// let color = Color::from("#abcdef");
+    /// assert_eq!(color.name(), "#abcdef");
+    /// ```
+    pub fn name(&self) -> String {
+        self.name_in(NameDictionary::W3cx11)
+    }
+
+    /// Returns the name of the color in the given dictionary, or its hex string.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, NameDictionary};
+    ///
+    /// assert_eq!(Color::from("#800000").name_in(NameDictionary::Basic), "maroon");
+    /// assert_eq!(Color::from("#dc143c").name_in(NameDictionary::Basic), "#dc143c");
+    /// ```
+    pub fn name_in(&self, dictionary: NameDictionary) -> String {
+        match self.names_in(dictionary).first() {
+            Some(name) => String::from(*name),
+            None => self.hex(),
+        }
+    }
+
+    /// Returns every name of the color, sorted alphabetically.
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// assert_eq!(Color::from("#0ff").names(), ["aqua", "cyan"]);
+    /// assert!(Color::from("#abcdef").names().is_empty());
+    /// ```
+    pub fn names(&self) -> Vec<&'static str> {
+        self.names_in(NameDictionary::W3cx11)
+    }
+
+    /// Returns every name of the color in the given dictionary, sorted alphabetically.
+    pub fn names_in(&self, dictionary: NameDictionary) -> Vec<&'static str> {
+        // named colors are opaque
+        if (self.alpha() * 255.).round() < 255. {
+            return Vec::new();
+        }
+        let num = self.num();
+        let by_hex = dictionary.by_hex();
+        let start = by_hex.partition_point(|(named, _)| *named < num);
+        by_hex[start..]
+            .iter()
+            .take_while(|(named, _)| *named == num)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    /// Returns the nearest named color by CIEDE2000 difference, with the difference.
+    ///
+    /// The alpha is ignored. Ties are broken alphabetically.
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// let (name, delta_e) = Color::from("#fe0000").nearest_name();
+    /// assert_eq!(name, "red");
+    /// assert!(delta_e < 1.);
+    /// ```
+    pub fn nearest_name(&self) -> (&'static str, f64) {
+        self.nearest_name_in(NameDictionary::W3cx11)
+    }
+
+    /// Returns the nearest color of the given dictionary by CIEDE2000 difference,
+    /// with the difference.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, NameDictionary};
+    ///
+    /// let (name, _) = Color::from("#dc143c").nearest_name_in(NameDictionary::Basic);
+    /// assert_eq!(name, "red");
+    /// ```
+    pub fn nearest_name_in(&self, dictionary: NameDictionary) -> (&'static str, f64) {
+        let lab = self.lab();
+        dictionary
+            .labs()
+            .iter()
+            .map(|(name, named)| (*name, ciede2000(lab, *named)))
+            .fold(("", f64::MAX), |nearest, candidate| {
+                if candidate.1 < nearest.1 {
+                    candidate
+                } else {
+                    nearest
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name() {
+        let color = Color::from("#abcdef");
+        assert_eq!(color.name(), "#abcdef");
+
+        let color = Color::from("rgb(0, 250, 154)");
+        assert_eq!(color.name(), "mediumspringgreen");
+
+        let color = Color::from("#00fa9a");
+        assert_eq!(color.name(), "mediumspringgreen");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(Color::from("#f0f").names(), ["fuchsia", "magenta"]);
+        assert_eq!(Color::from("#f0f").name(), "fuchsia");
+        assert_eq!(Color::from("#a9a9a9").names(), ["darkgray", "darkgrey"]);
+        assert_eq!(Color::from("#ff000080").names(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_names_in() {
+        let color = Color::from("#0ff");
+        assert_eq!(color.names_in(NameDictionary::W3cx11), ["aqua", "cyan"]);
+        assert_eq!(color.names_in(NameDictionary::Basic), ["aqua"]);
+        assert_eq!(
+            Color::from("#008000").name_in(NameDictionary::Basic),
+            "green"
+        );
+        assert_eq!(
+            Color::from("#abcdef").name_in(NameDictionary::Basic),
+            "#abcdef"
+        );
+
+        assert_eq!(
+            Color::from("silver").nearest_name_in(NameDictionary::Basic),
+            ("silver", 0.)
+        );
+        let (name, _) = Color::from("darkgreen").nearest_name_in(NameDictionary::Basic);
+        assert_eq!(name, "green");
+    }
+
+    #[test]
+    fn test_dictionaries() {
+        for dictionary in [NameDictionary::W3cx11, NameDictionary::Basic] {
+            let by_hex = dictionary.by_hex();
+            assert!(by_hex.windows(2).all(|pair| pair[0] < pair[1]));
+            let labs = dictionary.labs();
+            assert!(labs.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert_eq!(labs.len(), by_hex.len());
+            for (num, name) in by_hex {
+                let (_, lab) = labs.iter().find(|(named, _)| named == name).unwrap();
+                assert!(ciede2000(Color::from(*num).lab(), *lab) < 1e-9, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearest_name() {
+        assert_eq!(Color::from("white").nearest_name(), ("white", 0.));
+        let (name, delta_e) = Color::from("#dc143d").nearest_name();
+        assert_eq!(name, "crimson");
+        assert!(delta_e > 0.);
+    }
+}
@@ -1,9 +1,14 @@
-use crate::{utils::conversion, Color};
+use alloc::string::String;
+
+use crate::{
+    utils::conversion, Adaptation, Cam16, Color, CssNotation, GamutMapping, HexOptions, RgbSpace,
+    ViewingConditions, WhitePoint, YCbCrRange, YCbCrStandard,
+};
 
 impl Color {
     /// Return the color as hexadecimal string.
     ///
-    /// The hex string will include the alpha channel if it's less than 1.
+    /// The hex string will include the alpha channel if it rounds to less than `ff`.
     ///
     /// For example:
     /// ```
@@ -17,6 +22,24 @@ impl Color {
         conversion::hex::rgb2hex(self.rgba())
     }
 
+    /// Return the color as hexadecimal string, formatted with the options.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, HexAlpha, HexOptions};
+    ///
+    /// let color = Color::from("#ff00cc");
+    /// let options = HexOptions {
+    ///     alpha: HexAlpha::Always,
+    ///     short: true,
+    ///     uppercase: true,
+    ///     prefix: "0x",
+    /// };
+    /// assert_eq!(color.hex_with(&options), "0xF0CF");
+    /// ```
+    pub fn hex_with(&self, options: &HexOptions) -> String {
+        conversion::hex::rgb2hex_with(self.rgba(), options)
+    }
+
     pub fn rgb(&self) -> (u8, u8, u8) {
         let (r, g, b, _) = self.rgba;
         (r, g, b)
@@ -34,6 +57,33 @@ impl Color {
         conversion::hsv::rgb2hsv(self.rgb())
     }
 
+    /// HWB (hue, whiteness, blackness)
+    ///
+    /// <https://en.wikipedia.org/wiki/HWB_color_model>
+    ///
+    /// The whiteness and blackness are in the range [0, 1].
+    pub fn hwb(&self) -> (f64, f64, f64) {
+        conversion::hwb::rgb2hwb(self.rgb())
+    }
+
+    /// HSI (hue, saturation, intensity)
+    ///
+    /// <https://en.wikipedia.org/wiki/HSL_and_HSV>
+    ///
+    /// The saturation and intensity are in the range [0, 1].
+    pub fn hsi(&self) -> (f64, f64, f64) {
+        conversion::hsi::rgb2hsi(self.rgb())
+    }
+
+    /// HCG (hue, chroma, grayness)
+    ///
+    /// <https://github.com/acterhd/hcg-color>
+    ///
+    /// The chroma and grayness are in the range [0, 1].
+    pub fn hcg(&self) -> (f64, f64, f64) {
+        conversion::hcg::rgb2hcg(self.rgb())
+    }
+
     pub fn hsla(&self) -> (f64, f64, f64, f64) {
         let (h, s, l) = self.hsl();
         let a = self.alpha();
@@ -44,6 +94,193 @@ impl Color {
         conversion::lab::rgb2lab(self.rgb())
     }
 
+    /// CIE-L*ab relative to the given reference white.
+    ///
+    /// The sRGB (D65) color is adapted to the white with the Bradford transform.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, WhitePoint};
+    ///
+    /// let (l, a, b) = Color::from("white").lab_with(WhitePoint::D50);
+    /// assert!((l - 100.).abs() < 0.01);
+    /// assert!(a.abs() < 0.01);
+    /// assert!(b.abs() < 0.01);
+    /// ```
+    pub fn lab_with(&self, white: WhitePoint) -> (f64, f64, f64) {
+        let xyz = Adaptation::Bradford.adapt(self.xyz(), WhitePoint::D65, white);
+        conversion::lab::xyz2lab(xyz, white)
+    }
+
+    /// CIE LCh, the polar form of CIE-L*ab.
+    pub fn lch(&self) -> (f64, f64, f64) {
+        conversion::lch::lab2lch(self.lab())
+    }
+
+    /// OKLab, a perceptual color space.
+    ///
+    /// <https://bottosson.github.io/posts/oklab/>
+    pub fn oklab(&self) -> (f64, f64, f64) {
+        conversion::oklab::rgb2oklab(self.rgb())
+    }
+
+    /// OKLCh, the polar form of OKLab.
+    pub fn oklch(&self) -> (f64, f64, f64) {
+        conversion::lch::lab2lch(self.oklab())
+    }
+
+    /// CIE-L*uv
+    ///
+    /// <https://en.wikipedia.org/wiki/CIELUV>
+    pub fn luv(&self) -> (f64, f64, f64) {
+        conversion::luv::rgb2luv(self.rgb())
+    }
+
+    /// CIE LCh(uv), the polar form of CIE-L*uv.
+    pub fn lchuv(&self) -> (f64, f64, f64) {
+        conversion::lch::lab2lch(self.luv())
+    }
+
+    /// HSLuv, a human-friendly alternative to HSL based on CIE LCh(uv)
+    ///
+    /// <https://www.hsluv.org/>
+    ///
+    /// The saturation and lightness are in the range [0, 100].
+    pub fn hsluv(&self) -> (f64, f64, f64) {
+        conversion::hsluv::rgb2hsluv(self.rgb())
+    }
+
+    /// HPLuv, the pastel variant of HSLuv
+    ///
+    /// Only colors with a saturation in [0, 100] keep it at any hue.
+    pub fn hpluv(&self) -> (f64, f64, f64) {
+        conversion::hsluv::rgb2hpluv(self.rgb())
+    }
+
+    /// CIE XYZ, relative to the D65 white of sRGB (`y` of white is 1).
+    ///
+    /// <https://en.wikipedia.org/wiki/CIE_1931_color_space>
+    pub fn xyz(&self) -> (f64, f64, f64) {
+        conversion::xyz::rgb2xyz(self.rgb())
+    }
+
+    /// CIE xyY, the chromaticity `x`, `y` and the luminance `Y`.
+    pub fn xyy(&self) -> (f64, f64, f64) {
+        conversion::xyz::xyz2xyy(self.xyz())
+    }
+
+    /// CAM16 color appearance, seen in the default viewing conditions.
+    ///
+    /// <https://doi.org/10.1002/col.22131>
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// let cam = Color::from("red").cam16();
+    /// assert!((cam.j - 46.445).abs() < 0.05);
+    /// assert!((cam.h - 27.408).abs() < 0.05);
+    /// ```
+    pub fn cam16(&self) -> Cam16 {
+        self.cam16_with(&ViewingConditions::default())
+    }
+
+    /// CAM16 color appearance, seen in the given viewing conditions.
+    pub fn cam16_with(&self, vc: &ViewingConditions) -> Cam16 {
+        Cam16::from_xyz(self.xyz(), vc)
+    }
+
+    /// CAM16-UCS `J'`, `a'` and `b'`, seen in the default viewing conditions.
+    pub fn cam16_ucs(&self) -> (f64, f64, f64) {
+        self.cam16().ucs()
+    }
+
+    /// HCT (hue, chroma, tone), the color space of Material Design
+    ///
+    /// The hue and chroma are the ones of CAM16, the tone is the CIE L* in [0, 100].
+    ///
+    /// <https://material.io/blog/science-of-color-design>
+    pub fn hct(&self) -> (f64, f64, f64) {
+        conversion::hct::rgb2hct(self.rgb())
+    }
+
+    /// Channels (`0..1`) of the color in the given RGB color space.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, RgbSpace};
+    ///
+    /// let (r, g, b) = Color::from("white").rgb_space(&RgbSpace::DISPLAY_P3);
+    /// assert!((r - 1.).abs() < 0.001);
+    /// ```
+    pub fn rgb_space(&self, space: &RgbSpace) -> (f64, f64, f64) {
+        let (r, g, b) = self.rgb();
+        let rgb = (r as f64 / 255., g as f64 / 255., b as f64 / 255.);
+        RgbSpace::SRGB.convert(rgb, space)
+    }
+
+    /// Whether the color is inside the gamut of the given RGB color space.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, RgbSpace};
+    ///
+    /// assert!(Color::from("red").in_gamut(&RgbSpace::DISPLAY_P3));
+    /// ```
+    pub fn in_gamut(&self, space: &RgbSpace) -> bool {
+        conversion::gamut::in_gamut(self.rgb_space(space))
+    }
+
+    /// Bring the color into the gamut of the given RGB color space.
+    ///
+    /// Colors already inside the gamut are returned unchanged.
+    pub fn to_gamut(&self, space: &RgbSpace, method: GamutMapping) -> Color {
+        if self.in_gamut(space) {
+            return *self;
+        }
+        let rgb = conversion::gamut::map_xyz(self.xyz(), space, method);
+        let mut color = Color::from_rgb_space(rgb, space);
+        color.set_alpha(self.alpha());
+        color
+    }
+
+    /// Return the color as CSS `color()` string in the given RGB color space.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, RgbSpace};
+    ///
+    /// let color = Color::from("red");
+    /// assert_eq!(color.css_color(&RgbSpace::DISPLAY_P3), "color(display-p3 0.9175 0.2003 0.1386)");
+    /// ```
+    pub fn css_color(&self, space: &RgbSpace) -> String {
+        self.css(CssNotation::Color(*space))
+    }
+
+    /// YCbCr (luma, blue-difference and red-difference chroma) as 8-bit code values.
+    ///
+    /// <https://en.wikipedia.org/wiki/YCbCr>
+    ///
+    /// ```
+    /// use chroma_rust::{Color, YCbCrRange, YCbCrStandard};
+    ///
+    /// let color = Color::from("white");
+    /// let (y, cb, cr) = color.ycbcr(YCbCrStandard::Bt709, YCbCrRange::Limited);
+    /// assert_eq!((y.round(), cb.round(), cr.round()), (235., 128., 128.));
+    /// ```
+    pub fn ycbcr(&self, standard: YCbCrStandard, range: YCbCrRange) -> (f64, f64, f64) {
+        conversion::ycbcr::rgb2ycbcr(self.rgb(), standard, range)
+    }
+
+    /// YUV, the analog BT.601 encoding used by PAL
+    ///
+    /// <https://en.wikipedia.org/wiki/Y%E2%80%B2UV>
+    pub fn yuv(&self) -> (f64, f64, f64) {
+        conversion::yuv::rgb2yuv(self.rgb())
+    }
+
+    /// YIQ, the analog encoding used by NTSC
+    ///
+    /// <https://en.wikipedia.org/wiki/YIQ>
+    pub fn yiq(&self) -> (f64, f64, f64) {
+        conversion::yiq::rgb2yiq(self.rgb())
+    }
+
     /// CMYK (cyan, magenta, yellow, black)
     ///
     /// <https://en.wikipedia.org/wiki/CMYK_color_model>
@@ -67,35 +304,6 @@ impl Color {
         conversion::num::rgb2num(self.rgb())
     }
 
-    /// Returns the named color.
-    ///
-    /// Falls back to hexadecimal RGB string, if the color isn't present.
-    ///
-    /// Named color from [*w3cx11*](http://www.w3.org/TR/css3-color/#svg-color)
-    ///
-    /// ```
-    /// use chroma_rust::Color;
-    ///
-    /// let color = Color::from("#ff0");
-    /// assert_eq!(color.name(), "yellow");
-    ///
-    /// let color = Color::from("#abcdef");
-    /// assert_eq!(color.name(), "#abcdef");
-    /// ```
-    pub fn name(&self) -> String {
-        let hex = self.hex();
-
-        let result = crate::W3CX11
-            .clone()
-            .into_iter()
-            .find(|(_k, v)| v.to_string() == hex);
-
-        match result {
-            Some((k, _v)) => String::from(k),
-            None => hex,
-        }
-    }
-
     /// Estimate the temperature in Kelvin of any given color, though this makes the only sense for colors from the temperature gradient above.
     /// More see [color-temperature](https://github.com/neilbartlett/color-temperature).
     pub fn temperature(&self) -> f64 {
@@ -106,6 +314,7 @@ impl Color {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_hex() {
@@ -146,6 +355,58 @@ mod tests {
         assert_eq!(v, 1.0);
     }
 
+    #[test]
+    fn test_hwb() {
+        let color = Color::from("cyan");
+        assert_eq!(color.hwb(), (180., 0., 0.));
+
+        let color = Color::from("#99cc66");
+        let (h, w, b) = color.hwb();
+        assert_eq!(h, 90.);
+        assert!((w - 0.4).abs() < 0.001);
+        assert!((b - 0.2).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_hsi() {
+        let color = Color::from("blue");
+        let (h, s, i) = color.hsi();
+        assert!((h - 240.).abs() < 0.001);
+        assert!((s - 1.).abs() < 0.001);
+        assert!((i - 1. / 3.).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_hcg() {
+        let color = Color::from("cyan");
+        assert_eq!(color.hcg(), (180., 1., 0.));
+    }
+
+    #[test]
+    fn test_ycbcr() {
+        let color = Color::from("red");
+        let (y, cb, cr) = color.ycbcr(YCbCrStandard::Bt601, YCbCrRange::Full);
+        assert!((y - 76.245).abs() < 0.01);
+        assert!((cb - 84.972).abs() < 0.01);
+        assert!((cr - 255.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_yuv() {
+        let (y, u, v) = Color::from("red").yuv();
+        assert!((y - 0.299).abs() < 0.001);
+        assert!((u + 0.14713).abs() < 0.001);
+        assert!((v - 0.615).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_yiq() {
+        let (y, i, q) = Color::from("red").yiq();
+        assert!((y - 0.299).abs() < 0.001);
+        assert!((i - 0.5959).abs() < 0.001);
+        assert!((q - 0.2115).abs() < 0.001);
+    }
+
     #[test]
     fn test_hsla() {
         let color = Color::new(255, 255, 255, 1.0);
@@ -167,15 +428,152 @@ mod tests {
     }
 
     #[test]
-    fn test_name() {
-        let color = Color::from("#abcdef");
-        assert_eq!(color.name(), "#abcdef");
+    fn test_lab_with() {
+        let color = Color::from("orange");
+        assert_eq!(color.lab_with(WhitePoint::D65), color.lab());
+
+        // lab(54.29 80.8 69.89) in CSS Color 4
+        let (l, a, b) = Color::from("red").lab_with(WhitePoint::D50);
+        assert!((l - 54.29).abs() < 0.05);
+        assert!((a - 80.80).abs() < 0.05);
+        assert!((b - 69.89).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_rgb_space() {
+        let color = Color::from("red");
+        let (r, g, b) = color.rgb_space(&RgbSpace::REC2020);
+        assert!((r - 0.7920).abs() < 0.001);
+        assert!((g - 0.2310).abs() < 0.001);
+        assert!((b - 0.0738).abs() < 0.001);
+    }
 
-        let color = Color::from("rgb(0, 250, 154)");
-        assert_eq!(color.name(), "mediumspringgreen");
+    #[test]
+    fn test_css_color() {
+        let color = Color::from("white");
+        assert_eq!(color.css_color(&RgbSpace::SRGB), "color(srgb 1 1 1)");
+
+        let color = Color::from("rgba(255, 0, 0, 0.5)");
+        assert_eq!(
+            color.css_color(&RgbSpace::DISPLAY_P3),
+            "color(display-p3 0.9175 0.2003 0.1386 / 0.5)"
+        );
+        assert_eq!(
+            Color::from(color.css_color(&RgbSpace::DISPLAY_P3).as_str()),
+            color
+        );
+    }
 
-        let color = Color::from("#00fa9a");
-        assert_eq!(color.name(), "mediumspringgreen");
+    #[test]
+    fn test_lch() {
+        // [53.24,104.55,40]
+        let (l, c, h) = Color::from("red").lch();
+        assert!((l - 53.24).abs() < 0.01);
+        assert!((c - 104.55).abs() < 0.01);
+        assert!((h - 40.).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_oklch() {
+        let (l, c, h) = Color::from("red").oklch();
+        assert!((l - 0.628).abs() < 0.001);
+        assert!((c - 0.2577).abs() < 0.001);
+        assert!((h - 29.23).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_luv() {
+        let (l, u, v) = Color::from("red").luv();
+        assert!((l - 53.24).abs() < 0.01);
+        assert!((u - 175.01).abs() < 0.01);
+        assert!((v - 37.76).abs() < 0.01);
+
+        let (l, c, h) = Color::from("red").lchuv();
+        assert!((l - 53.24).abs() < 0.01);
+        assert!((c - 179.04).abs() < 0.01);
+        assert!((h - 12.18).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_hsluv() {
+        let (h, s, l) = Color::from("red").hsluv();
+        assert!((h - 12.177).abs() < 0.01);
+        assert!((s - 100.).abs() < 0.01);
+        assert!((l - 53.237).abs() < 0.01);
+
+        let (h, s, l) = Color::from("white").hpluv();
+        assert_eq!((h, s, l), (0., 0., 100.));
+    }
+
+    #[test]
+    fn test_cam16() {
+        let color = Color::from("#4285f4");
+        let ucs = color.cam16_ucs();
+        assert_eq!(Color::from_cam16_ucs(ucs), color);
+        assert_eq!(Color::vec_mode2color(color.mode("cam16"), "cam16"), color);
+
+        let vc = ViewingConditions {
+            surround: crate::Surround::Dark,
+            ..Default::default()
+        };
+        let cam = color.cam16_with(&vc);
+        assert_ne!(cam, color.cam16());
+        assert_eq!(Color::from_cam16(&cam, &vc), color);
+    }
+
+    #[test]
+    fn test_hct() {
+        let (h, c, t) = Color::from("blue").hct();
+        assert!((h - 282.788).abs() < 0.05);
+        assert!((c - 87.230).abs() < 0.05);
+        assert!((t - 32.302).abs() < 0.05);
+        assert_eq!(Color::vec_mode2color(vec![h, c, t], "hct").hex(), "#0000ff");
+    }
+
+    #[test]
+    fn test_in_gamut() {
+        let space = RgbSpace {
+            name: "narrow",
+            primaries: [(0.6, 0.34), (0.32, 0.55), (0.18, 0.12)],
+            ..RgbSpace::SRGB
+        };
+        assert!(Color::from("red").in_gamut(&RgbSpace::SRGB));
+        assert!(Color::from("red").in_gamut(&RgbSpace::REC2020));
+        assert!(Color::from("white").in_gamut(&space));
+        assert!(!Color::from("red").in_gamut(&space));
+    }
+
+    #[test]
+    fn test_to_gamut() {
+        let space = RgbSpace {
+            name: "narrow",
+            primaries: [(0.6, 0.34), (0.32, 0.55), (0.18, 0.12)],
+            ..RgbSpace::SRGB
+        };
+        let red = Color::from("red");
+        assert_eq!(red.to_gamut(&RgbSpace::SRGB, GamutMapping::Css), red);
+
+        let mapped = red.to_gamut(&space, GamutMapping::Css);
+        assert!(mapped.in_gamut(&space));
+        assert!((mapped.oklch().2 - red.oklch().2).abs() < 1.);
+    }
+
+    #[test]
+    fn test_xyz() {
+        let color = Color::from("white");
+        let (x, y, z) = color.xyz();
+        assert!((x - 0.9505).abs() < 0.001);
+        assert!((y - 1.).abs() < 0.001);
+        assert!((z - 1.089).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_xyy() {
+        let color = Color::from("white");
+        let (x, y, big_y) = color.xyy();
+        assert!((x - 0.3127).abs() < 0.001);
+        assert!((y - 0.3290).abs() < 0.001);
+        assert!((big_y - 1.).abs() < 0.001);
     }
 
     #[test]
@@ -0,0 +1,376 @@
+use alloc::string::String;
+
+use crate::utils::conversion;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::{Adaptation, Color, GamutMapping, RgbSpace, WhitePoint, Xyz};
+
+/// The color space of a design token color.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TokenColorSpace {
+    /// An RGB color space, with channels in `0..1`
+    Rgb(RgbSpace),
+    /// Hue in degrees, saturation and lightness in `0..100`
+    Hsl,
+    /// Hue in degrees, whiteness and blackness in `0..100`
+    Hwb,
+    /// CIE Lab relative to the D50 white, like CSS
+    Lab,
+    /// CIE LCh relative to the D50 white, like CSS
+    Lch,
+    Oklab,
+    Oklch,
+    XyzD65,
+    XyzD50,
+}
+
+impl TokenColorSpace {
+    /// The identifier of the color space, e.g. `display-p3` or `oklch`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenColorSpace::Rgb(space) => space.name,
+            TokenColorSpace::Hsl => "hsl",
+            TokenColorSpace::Hwb => "hwb",
+            TokenColorSpace::Lab => "lab",
+            TokenColorSpace::Lch => "lch",
+            TokenColorSpace::Oklab => "oklab",
+            TokenColorSpace::Oklch => "oklch",
+            TokenColorSpace::XyzD65 => "xyz-d65",
+            TokenColorSpace::XyzD50 => "xyz-d50",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<TokenColorSpace> {
+        match name {
+            "hsl" => Some(TokenColorSpace::Hsl),
+            "hwb" => Some(TokenColorSpace::Hwb),
+            "lab" => Some(TokenColorSpace::Lab),
+            "lch" => Some(TokenColorSpace::Lch),
+            "oklab" => Some(TokenColorSpace::Oklab),
+            "oklch" => Some(TokenColorSpace::Oklch),
+            "xyz-d65" | "xyz" => Some(TokenColorSpace::XyzD65),
+            "xyz-d50" => Some(TokenColorSpace::XyzD50),
+            name => RgbSpace::from_name(name).map(TokenColorSpace::Rgb),
+        }
+    }
+}
+
+/// A color in the format of the
+/// [Design Tokens Community Group](https://www.designtokens.org/tr/drafts/color/),
+/// e.g. `{ "colorSpace": "srgb", "components": [1, 0.5, 0], "alpha": 1, "hex": "#ff8000" }`.
+///
+/// It's (de)serialized with the `serde` feature, a `"none"` component being read as 0.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TokenColor {
+    pub color_space: TokenColorSpace,
+    pub components: [f64; 3],
+    pub alpha: f64,
+    /// A 6-digit hex fallback
+    pub hex: Option<String>,
+}
+
+impl Color {
+    /// Return the color as a design token in the given color space.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, RgbSpace, TokenColorSpace};
+    ///
+    /// let token = Color::from("rgb(255 128 0 / 0.5)").design_token(TokenColorSpace::Rgb(RgbSpace::SRGB));
+    /// assert_eq!(token.components[0], 1.);
+    /// assert_eq!(token.alpha, 0.5);
+    /// assert_eq!(token.hex.as_deref(), Some("#ff8000"));
+    /// ```
+    pub fn design_token(&self, color_space: TokenColorSpace) -> TokenColor {
+        let percent = |v: f64| v * 100.;
+        let (c0, c1, c2) = match color_space {
+            TokenColorSpace::Rgb(space) => self.rgb_space(&space),
+            TokenColorSpace::Hsl => {
+                let (h, s, l) = self.hsl();
+                (h, percent(s), percent(l))
+            }
+            TokenColorSpace::Hwb => {
+                let (h, w, b) = self.hwb();
+                (h, percent(w), percent(b))
+            }
+            TokenColorSpace::Lab => self.lab_with(WhitePoint::D50),
+            TokenColorSpace::Lch => conversion::lch::lab2lch(self.lab_with(WhitePoint::D50)),
+            TokenColorSpace::Oklab => self.oklab(),
+            TokenColorSpace::Oklch => self.oklch(),
+            TokenColorSpace::XyzD65 => self.xyz(),
+            TokenColorSpace::XyzD50 => {
+                Adaptation::Bradford.adapt(self.xyz(), WhitePoint::D65, WhitePoint::D50)
+            }
+        };
+        let mut hex = *self;
+        hex.set_alpha(1.);
+        TokenColor {
+            color_space,
+            components: [c0, c1, c2],
+            alpha: self.alpha(),
+            hex: Some(hex.hex()),
+        }
+    }
+
+    /// Construct a color from a design token, bringing it into the sRGB gamut.
+    ///
+    /// Perceptual color spaces are mapped like CSS, the others are clipped.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, TokenColor, TokenColorSpace};
+    ///
+    /// let token = TokenColor {
+    ///     color_space: TokenColorSpace::Oklch,
+    ///     components: [0.628, 0.2577, 29.23],
+    ///     alpha: 1.,
+    ///     hex: None,
+    /// };
+    /// assert_eq!(Color::from_design_token(&token).hex(), "#ff0000");
+    /// ```
+    pub fn from_design_token(token: &TokenColor) -> Color {
+        let [c0, c1, c2] = token.components;
+        let from_xyz_d50 = |xyz| {
+            let xyz = Adaptation::Bradford.adapt(xyz, WhitePoint::D50, WhitePoint::D65);
+            Color::from_lab(
+                conversion::lab::xyz2lab(xyz, WhitePoint::D65),
+                GamutMapping::Css,
+            )
+        };
+        let mut color = match token.color_space {
+            TokenColorSpace::Rgb(space) => Color::from_rgb_space((c0, c1, c2), &space),
+            TokenColorSpace::Hsl => {
+                let (r, g, b) =
+                    conversion::hsl::hsl2rgb((c0.rem_euclid(360.), c1 / 100., c2 / 100.));
+                Color::new(r, g, b, 1.)
+            }
+            TokenColorSpace::Hwb => {
+                let (r, g, b) =
+                    conversion::hwb::hwb2rgb((c0.rem_euclid(360.), c1 / 100., c2 / 100.));
+                Color::new(r, g, b, 1.)
+            }
+            TokenColorSpace::Lab => {
+                from_xyz_d50(conversion::lab::lab2xyz((c0, c1, c2), WhitePoint::D50))
+            }
+            TokenColorSpace::Lch => {
+                let lab = conversion::lch::lch2lab((c0, c1, c2));
+                from_xyz_d50(conversion::lab::lab2xyz(lab, WhitePoint::D50))
+            }
+            TokenColorSpace::Oklab => Color::from_oklab((c0, c1, c2), GamutMapping::Css),
+            TokenColorSpace::Oklch => Color::from_oklch((c0, c1, c2), GamutMapping::Css),
+            TokenColorSpace::XyzD65 => Color::from(Xyz::new(c0, c1, c2)),
+            TokenColorSpace::XyzD50 => {
+                let xyz =
+                    Adaptation::Bradford.adapt((c0, c1, c2), WhitePoint::D50, WhitePoint::D65);
+                Color::from(Xyz::from(xyz))
+            }
+        };
+        color.set_alpha(token.alpha);
+        color
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use alloc::{format, string::String};
+    use core::fmt;
+
+    use serde::de::{self, MapAccess, Visitor};
+    use serde::ser::SerializeStruct;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{TokenColor, TokenColorSpace};
+
+    impl Serialize for TokenColor {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("TokenColor", 4)?;
+            state.serialize_field("colorSpace", self.color_space.name())?;
+            state.serialize_field("components", &self.components)?;
+            state.serialize_field("alpha", &self.alpha)?;
+            match &self.hex {
+                Some(hex) => state.serialize_field("hex", hex)?,
+                None => state.skip_field("hex")?,
+            }
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TokenColor {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(TokenColorVisitor)
+        }
+    }
+
+    struct TokenColorVisitor;
+
+    impl<'de> Visitor<'de> for TokenColorVisitor {
+        type Value = TokenColor;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a design token color")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TokenColor, A::Error> {
+            let (mut color_space, mut components, mut alpha, mut hex) = (None, None, None, None);
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "colorSpace" => {
+                        let name: String = map.next_value()?;
+                        let space = TokenColorSpace::from_name(&name).ok_or_else(|| {
+                            de::Error::custom(format!("Color space not found: `{}`", name))
+                        })?;
+                        color_space = Some(space);
+                    }
+                    "components" => {
+                        let [c0, c1, c2]: [Component; 3] = map.next_value()?;
+                        components = Some([c0.0, c1.0, c2.0]);
+                    }
+                    "alpha" => alpha = Some(map.next_value()?),
+                    "hex" => hex = Some(map.next_value()?),
+                    _ => {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+            }
+            Ok(TokenColor {
+                color_space: color_space.ok_or_else(|| de::Error::missing_field("colorSpace"))?,
+                components: components.ok_or_else(|| de::Error::missing_field("components"))?,
+                alpha: alpha.unwrap_or(1.),
+                hex,
+            })
+        }
+    }
+
+    /// A component, `"none"` being read as 0.
+    struct Component(f64);
+
+    impl<'de> Deserialize<'de> for Component {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ComponentVisitor)
+        }
+    }
+
+    struct ComponentVisitor;
+
+    impl<'de> Visitor<'de> for ComponentVisitor {
+        type Value = Component;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a number or \"none\"")
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Component, E> {
+            Ok(Component(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Component, E> {
+            Ok(Component(v as f64))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Component, E> {
+            Ok(Component(v as f64))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Component, E> {
+            match v {
+                "none" => Ok(Component(0.)),
+                _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+
+    fn spaces() -> Vec<TokenColorSpace> {
+        vec![
+            TokenColorSpace::Rgb(RgbSpace::SRGB),
+            TokenColorSpace::Rgb(RgbSpace::SRGB_LINEAR),
+            TokenColorSpace::Rgb(RgbSpace::DISPLAY_P3),
+            TokenColorSpace::Rgb(RgbSpace::REC2020),
+            TokenColorSpace::Rgb(RgbSpace::ADOBE_RGB),
+            TokenColorSpace::Rgb(RgbSpace::PROPHOTO),
+            TokenColorSpace::Hsl,
+            TokenColorSpace::Hwb,
+            TokenColorSpace::Lab,
+            TokenColorSpace::Lch,
+            TokenColorSpace::Oklab,
+            TokenColorSpace::Oklch,
+            TokenColorSpace::XyzD65,
+            TokenColorSpace::XyzD50,
+        ]
+    }
+
+    #[test]
+    fn test_token_color_space_name() {
+        for space in spaces() {
+            assert_eq!(TokenColorSpace::from_name(space.name()), Some(space));
+        }
+        assert_eq!(TokenColorSpace::from_name("cmyk"), None);
+    }
+
+    #[test]
+    fn test_design_token() {
+        let color = Color::from("#336699");
+        let token = color.design_token(TokenColorSpace::Hsl);
+        assert_eq!(token.components.map(|c| c.round()), [210., 50., 40.]);
+        assert_eq!(token.alpha, 1.);
+        assert_eq!(token.hex.as_deref(), Some("#336699"));
+
+        // CSS lab() of white is relative to D50
+        let token = Color::from("white").design_token(TokenColorSpace::Lab);
+        let [l, a, b] = token.components;
+        assert!((l - 100.).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_design_token_round_trip() {
+        let mut color = Color::from("#336699");
+        color.set_alpha(0.5);
+        for space in spaces() {
+            let token = color.design_token(space);
+            assert_eq!(Color::from_design_token(&token), color, "{}", space.name());
+        }
+    }
+
+    #[test]
+    fn test_design_token_hue() {
+        let token = |color_space, components| TokenColor {
+            color_space,
+            components,
+            alpha: 1.,
+            hex: None,
+        };
+        let red = Color::from("red");
+        for hue in [360., -360., 720.] {
+            let hsl = token(TokenColorSpace::Hsl, [hue, 100., 50.]);
+            assert_eq!(Color::from_design_token(&hsl), red);
+            let hwb = token(TokenColorSpace::Hwb, [hue, 0., 0.]);
+            assert_eq!(Color::from_design_token(&hwb), red);
+        }
+        let hsl = token(TokenColorSpace::Hsl, [-30., 100., 50.]);
+        assert_eq!(Color::from_design_token(&hsl).hex(), "#ff0080");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_design_token_serde() {
+        let token = Color::from("#ff8000").design_token(TokenColorSpace::Rgb(RgbSpace::SRGB));
+        let json = serde_json::to_string(&token).unwrap();
+        assert_eq!(
+            json,
+            r##"{"colorSpace":"srgb","components":[1.0,0.5019607843137255,0.0],"alpha":1.0,"hex":"#ff8000"}"##
+        );
+        assert_eq!(serde_json::from_str::<TokenColor>(&json).unwrap(), token);
+
+        let json = r#"{ "colorSpace": "oklch", "components": [0.7, 0, "none"] }"#;
+        let token: TokenColor = serde_json::from_str(json).unwrap();
+        assert_eq!(token.components, [0.7, 0., 0.]);
+        assert_eq!(token.alpha, 1.);
+        assert_eq!(token.hex, None);
+
+        let json = r#"{ "colorSpace": "cmyk", "components": [0, 0, 0] }"#;
+        assert!(serde_json::from_str::<TokenColor>(json).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::{Color, GamutMapping, RgbSpace};
+
+/// A color as linear-light sRGB channels and alpha, for arithmetic.
+///
+/// Adding colors adds their light, e.g. to blend or accumulate samples.
+/// Channels may leave `0..1` in between, converting back to a [`Color`] clips them.
+///
+/// ```
+/// use chroma_rust::{Color, LinearRgb};
+///
+/// let red = LinearRgb::from(&Color::from("red"));
+/// let blue = LinearRgb::from(&Color::from("blue"));
+/// assert_eq!(Color::from((red + blue) / 2.).hex(), "#bc00bc");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LinearRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl LinearRgb {
+    pub fn new(r: f64, g: f64, b: f64, alpha: f64) -> LinearRgb {
+        LinearRgb { r, g, b, alpha }
+    }
+}
+
+/// A color as CIE Lab coordinates (D65, like [`Color::lab`]) and alpha, for arithmetic.
+///
+/// Converting back to a [`Color`] brings it into the sRGB gamut like CSS.
+///
+/// ```
+/// use chroma_rust::{Color, Lab};
+///
+/// let white = Lab::from(&Color::from("white"));
+/// let black = Lab::from(&Color::from("black"));
+/// let gray = Color::from(black + (white - black) * 0.5);
+/// assert!((gray.lab().0 - 50.).abs() < 0.5);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+impl Lab {
+    pub fn new(l: f64, a: f64, b: f64, alpha: f64) -> Lab {
+        Lab { l, a, b, alpha }
+    }
+}
+
+impl From<&Color> for LinearRgb {
+    fn from(color: &Color) -> Self {
+        let (r, g, b) = color.rgb_space(&RgbSpace::SRGB_LINEAR);
+        LinearRgb::new(r, g, b, color.alpha())
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(rgb: LinearRgb) -> Self {
+        let mut color = Color::from_rgb_space((rgb.r, rgb.g, rgb.b), &RgbSpace::SRGB_LINEAR);
+        color.set_alpha(rgb.alpha.clamp(0., 1.));
+        color
+    }
+}
+
+impl From<&Color> for Lab {
+    fn from(color: &Color) -> Self {
+        let (l, a, b) = color.lab();
+        Lab::new(l, a, b, color.alpha())
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        let mut color = Color::from_lab((lab.l, lab.a, lab.b), GamutMapping::Css);
+        color.set_alpha(lab.alpha.clamp(0., 1.));
+        color
+    }
+}
+
+/// Component-wise `Add` and `Sub`, and scaling by a float with `Mul` and `Div`.
+macro_rules! impl_vector_ops {
+    ($t:ident, $($field:ident),+) => {
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, other: $t) -> $t {
+                $t { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, other: $t) -> $t {
+                $t { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = $t;
+
+            fn mul(self, k: f64) -> $t {
+                $t { $($field: self.$field * k),+ }
+            }
+        }
+
+        impl Div<f64> for $t {
+            type Output = $t;
+
+            fn div(self, k: f64) -> $t {
+                $t { $($field: self.$field / k),+ }
+            }
+        }
+    };
+}
+
+impl_vector_ops!(LinearRgb, r, g, b, alpha);
+impl_vector_ops!(Lab, l, a, b, alpha);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_rgb_ops() {
+        let a = LinearRgb::new(0.2, 0.4, 0.6, 1.);
+        let b = LinearRgb::new(0.1, 0.1, 0.1, 0.5);
+        assert_eq!(a + b, LinearRgb::new(0.30000000000000004, 0.5, 0.7, 1.5));
+        assert_eq!(a - b, LinearRgb::new(0.1, 0.30000000000000004, 0.5, 0.5));
+        assert_eq!(b * 2., LinearRgb::new(0.2, 0.2, 0.2, 1.));
+        assert_eq!(a / 2., LinearRgb::new(0.1, 0.2, 0.3, 0.5));
+    }
+
+    #[test]
+    fn test_linear_rgb_color() {
+        let color = Color::from("#336699");
+        assert_eq!(Color::from(LinearRgb::from(&color)), color);
+
+        // light adds up, then is clipped
+        let red = LinearRgb::from(&Color::from("red"));
+        let green = LinearRgb::from(&Color::from("lime"));
+        assert_eq!(Color::from(red + green).hex(), "#ffff00");
+        assert_eq!(Color::from(red * 2.).hex(), "#ff0000");
+    }
+
+    #[test]
+    fn test_lab_color() {
+        let mut color = Color::from("#336699");
+        color.set_alpha(0.5);
+        let lab = Lab::from(&color);
+        assert_eq!(lab.alpha, 0.5);
+        assert_eq!(Color::from(lab), color);
+
+        let lighter = Color::from(lab + Lab::new(10., 0., 0., 0.));
+        assert!((lighter.lab().0 - color.lab().0 - 10.).abs() < 0.5);
+    }
+}
@@ -0,0 +1,98 @@
+/// The 16 basic colors of HTML 4 and CSS 1 keyed by RGB number, sorted by number
+///
+/// https://www.w3.org/TR/css-color-3/#html4
+pub static BASIC_BY_HEX: [(u32, &str); 16] = [
+    (0x000000, "black"),
+    (0x000080, "navy"),
+    (0x0000ff, "blue"),
+    (0x008000, "green"),
+    (0x008080, "teal"),
+    (0x00ff00, "lime"),
+    (0x00ffff, "aqua"),
+    (0x800000, "maroon"),
+    (0x800080, "purple"),
+    (0x808000, "olive"),
+    (0x808080, "gray"),
+    (0xc0c0c0, "silver"),
+    (0xff0000, "red"),
+    (0xff00ff, "fuchsia"),
+    (0xffff00, "yellow"),
+    (0xffffff, "white"),
+];
+
+/// The basic colors in CIE Lab (D65), sorted by name
+pub static BASIC_LAB: [(&str, (f64, f64, f64)); 16] = [
+    (
+        "aqua",
+        (91.11321981275862, -48.08752805875827, -14.131186091754412),
+    ),
+    ("black", (0.0, 0.0, 0.0)),
+    (
+        "blue",
+        (32.29701093285073, 79.18751984512221, -107.8601617541481),
+    ),
+    (
+        "fuchsia",
+        (60.32421212836874, 98.23431188800402, -60.82489220885006),
+    ),
+    (
+        "gray",
+        (
+            53.585015771669404,
+            -9.997846384113274e-6,
+            3.9991385536453095e-6,
+        ),
+    ),
+    (
+        "green",
+        (46.22743146876261, -51.69849552989111, 49.8968460010561),
+    ),
+    (
+        "lime",
+        (87.73472235279792, -86.1827164205346, 83.17932050269782),
+    ),
+    (
+        "maroon",
+        (25.53553096346318, 48.045128262358375, 38.05732084720349),
+    ),
+    (
+        "navy",
+        (12.971966857430804, 47.50228132471322, -64.70216212599588),
+    ),
+    (
+        "olive",
+        (51.86894337734398, -12.929464306735083, 56.67457900899427),
+    ),
+    (
+        "purple",
+        (29.784666617920195, 58.92789581199415, -36.48707709120311),
+    ),
+    (
+        "red",
+        (53.24079414130722, 80.09245959641109, 67.20319651585301),
+    ),
+    (
+        "silver",
+        (
+            77.7043667134314,
+            -1.3463270165381402e-5,
+            5.385308066152561e-6,
+        ),
+    ),
+    (
+        "teal",
+        (48.254093461861615, -28.846304196984917, -8.476885985257177),
+    ),
+    (
+        "white",
+        (
+            100.00000386666655,
+            -1.6666666158293708e-5,
+            6.666666463317483e-6,
+        ),
+    ),
+    (
+        "yellow",
+        (97.1392672243063, -21.553748216377066, 94.47797505367026),
+    ),
+];
@@ -1,165 +1,982 @@
-use std::collections::HashMap;
+/// X11 color names, sorted by name
+///
+/// http://www.w3.org/TR/css3-color/#svg-color
+pub static W3CX11: [(&str, &str); 154] = [
+    ("aliceblue", "#f0f8ff"),
+    ("antiquewhite", "#faebd7"),
+    ("aqua", "#00ffff"),
+    ("aquamarine", "#7fffd4"),
+    ("azure", "#f0ffff"),
+    ("beige", "#f5f5dc"),
+    ("bisque", "#ffe4c4"),
+    ("black", "#000000"),
+    ("blanchedalmond", "#ffebcd"),
+    ("blue", "#0000ff"),
+    ("blueviolet", "#8a2be2"),
+    ("brown", "#a52a2a"),
+    ("burlywood", "#deb887"),
+    ("cadetblue", "#5f9ea0"),
+    ("chartreuse", "#7fff00"),
+    ("chocolate", "#d2691e"),
+    ("coral", "#ff7f50"),
+    ("cornflowerblue", "#6495ed"),
+    ("cornsilk", "#fff8dc"),
+    ("crimson", "#dc143c"),
+    ("cyan", "#00ffff"),
+    ("darkblue", "#00008b"),
+    ("darkcyan", "#008b8b"),
+    ("darkgoldenrod", "#b8860b"),
+    ("darkgray", "#a9a9a9"),
+    ("darkgreen", "#006400"),
+    ("darkgrey", "#a9a9a9"),
+    ("darkkhaki", "#bdb76b"),
+    ("darkmagenta", "#8b008b"),
+    ("darkolivegreen", "#556b2f"),
+    ("darkorange", "#ff8c00"),
+    ("darkorchid", "#9932cc"),
+    ("darkred", "#8b0000"),
+    ("darksalmon", "#e9967a"),
+    ("darkseagreen", "#8fbc8f"),
+    ("darkslateblue", "#483d8b"),
+    ("darkslategray", "#2f4f4f"),
+    ("darkslategrey", "#2f4f4f"),
+    ("darkturquoise", "#00ced1"),
+    ("darkviolet", "#9400d3"),
+    ("deeppink", "#ff1493"),
+    ("deepskyblue", "#00bfff"),
+    ("dimgray", "#696969"),
+    ("dimgrey", "#696969"),
+    ("dodgerblue", "#1e90ff"),
+    ("firebrick", "#b22222"),
+    ("floralwhite", "#fffaf0"),
+    ("forestgreen", "#228b22"),
+    ("fuchsia", "#ff00ff"),
+    ("gainsboro", "#dcdcdc"),
+    ("ghostwhite", "#f8f8ff"),
+    ("gold", "#ffd700"),
+    ("goldenrod", "#daa520"),
+    ("gray", "#808080"),
+    ("green", "#008000"),
+    ("greenyellow", "#adff2f"),
+    ("grey", "#808080"),
+    ("honeydew", "#f0fff0"),
+    ("hotpink", "#ff69b4"),
+    ("indianred", "#cd5c5c"),
+    ("indigo", "#4b0082"),
+    ("ivory", "#fffff0"),
+    ("khaki", "#f0e68c"),
+    ("laserlemon", "#ffff54"),
+    ("lavender", "#e6e6fa"),
+    ("lavenderblush", "#fff0f5"),
+    ("lawngreen", "#7cfc00"),
+    ("lemonchiffon", "#fffacd"),
+    ("lightblue", "#add8e6"),
+    ("lightcoral", "#f08080"),
+    ("lightcyan", "#e0ffff"),
+    ("lightgoldenrod", "#fafad2"),
+    ("lightgoldenrodyellow", "#fafad2"),
+    ("lightgray", "#d3d3d3"),
+    ("lightgreen", "#90ee90"),
+    ("lightgrey", "#d3d3d3"),
+    ("lightpink", "#ffb6c1"),
+    ("lightsalmon", "#ffa07a"),
+    ("lightseagreen", "#20b2aa"),
+    ("lightskyblue", "#87cefa"),
+    ("lightslategray", "#778899"),
+    ("lightslategrey", "#778899"),
+    ("lightsteelblue", "#b0c4de"),
+    ("lightyellow", "#ffffe0"),
+    ("lime", "#00ff00"),
+    ("limegreen", "#32cd32"),
+    ("linen", "#faf0e6"),
+    ("magenta", "#ff00ff"),
+    ("maroon", "#800000"),
+    ("maroon2", "#7f0000"),
+    ("maroon3", "#b03060"),
+    ("mediumaquamarine", "#66cdaa"),
+    ("mediumblue", "#0000cd"),
+    ("mediumorchid", "#ba55d3"),
+    ("mediumpurple", "#9370db"),
+    ("mediumseagreen", "#3cb371"),
+    ("mediumslateblue", "#7b68ee"),
+    ("mediumspringgreen", "#00fa9a"),
+    ("mediumturquoise", "#48d1cc"),
+    ("mediumvioletred", "#c71585"),
+    ("midnightblue", "#191970"),
+    ("mintcream", "#f5fffa"),
+    ("mistyrose", "#ffe4e1"),
+    ("moccasin", "#ffe4b5"),
+    ("navajowhite", "#ffdead"),
+    ("navy", "#000080"),
+    ("oldlace", "#fdf5e6"),
+    ("olive", "#808000"),
+    ("olivedrab", "#6b8e23"),
+    ("orange", "#ffa500"),
+    ("orangered", "#ff4500"),
+    ("orchid", "#da70d6"),
+    ("palegoldenrod", "#eee8aa"),
+    ("palegreen", "#98fb98"),
+    ("paleturquoise", "#afeeee"),
+    ("palevioletred", "#db7093"),
+    ("papayawhip", "#ffefd5"),
+    ("peachpuff", "#ffdab9"),
+    ("peru", "#cd853f"),
+    ("pink", "#ffc0cb"),
+    ("plum", "#dda0dd"),
+    ("powderblue", "#b0e0e6"),
+    ("purple", "#800080"),
+    ("purple2", "#7f007f"),
+    ("purple3", "#a020f0"),
+    ("rebeccapurple", "#663399"),
+    ("red", "#ff0000"),
+    ("rosybrown", "#bc8f8f"),
+    ("royalblue", "#4169e1"),
+    ("saddlebrown", "#8b4513"),
+    ("salmon", "#fa8072"),
+    ("sandybrown", "#f4a460"),
+    ("seagreen", "#2e8b57"),
+    ("seashell", "#fff5ee"),
+    ("sienna", "#a0522d"),
+    ("silver", "#c0c0c0"),
+    ("skyblue", "#87ceeb"),
+    ("slateblue", "#6a5acd"),
+    ("slategray", "#708090"),
+    ("slategrey", "#708090"),
+    ("snow", "#fffafa"),
+    ("springgreen", "#00ff7f"),
+    ("steelblue", "#4682b4"),
+    ("tan", "#d2b48c"),
+    ("teal", "#008080"),
+    ("thistle", "#d8bfd8"),
+    ("tomato", "#ff6347"),
+    ("turquoise", "#40e0d0"),
+    ("violet", "#ee82ee"),
+    ("wheat", "#f5deb3"),
+    ("white", "#ffffff"),
+    ("whitesmoke", "#f5f5f5"),
+    ("yellow", "#ffff00"),
+    ("yellowgreen", "#9acd32"),
+];
 
-lazy_static! {
-    /// X11 color names
-    ///
-    /// http://www.w3.org/TR/css3-color/#svg-color
-    pub static ref W3CX11_HASHMAP: HashMap<&'static str, &'static str> = {
-        let mut m = HashMap::new();
-        m.insert("aliceblue", "#f0f8ff");
-        m.insert("antiquewhite", "#faebd7");
-        m.insert("aqua", "#00ffff");
-        m.insert("aquamarine", "#7fffd4");
-        m.insert("azure", "#f0ffff");
-        m.insert("beige", "#f5f5dc");
-        m.insert("bisque", "#ffe4c4");
-        m.insert("black", "#000000");
-        m.insert("blanchedalmond", "#ffebcd");
-        m.insert("blue", "#0000ff");
-        m.insert("blueviolet", "#8a2be2");
-        m.insert("brown", "#a52a2a");
-        m.insert("burlywood", "#deb887");
-        m.insert("cadetblue", "#5f9ea0");
-        m.insert("chartreuse", "#7fff00");
-        m.insert("chocolate", "#d2691e");
-        m.insert("coral", "#ff7f50");
-        m.insert("cornflowerblue", "#6495ed");
-        m.insert("cornsilk", "#fff8dc");
-        m.insert("crimson", "#dc143c");
-        m.insert("cyan", "#00ffff");
-        m.insert("darkblue", "#00008b");
-        m.insert("darkcyan", "#008b8b");
-        m.insert("darkgoldenrod", "#b8860b");
-        m.insert("darkgray", "#a9a9a9");
-        m.insert("darkgreen", "#006400");
-        m.insert("darkgrey", "#a9a9a9");
-        m.insert("darkkhaki", "#bdb76b");
-        m.insert("darkmagenta", "#8b008b");
-        m.insert("darkolivegreen", "#556b2f");
-        m.insert("darkorange", "#ff8c00");
-        m.insert("darkorchid", "#9932cc");
-        m.insert("darkred", "#8b0000");
-        m.insert("darksalmon", "#e9967a");
-        m.insert("darkseagreen", "#8fbc8f");
-        m.insert("darkslateblue", "#483d8b");
-        m.insert("darkslategray", "#2f4f4f");
-        m.insert("darkslategrey", "#2f4f4f");
-        m.insert("darkturquoise", "#00ced1");
-        m.insert("darkviolet", "#9400d3");
-        m.insert("deeppink", "#ff1493");
-        m.insert("deepskyblue", "#00bfff");
-        m.insert("dimgray", "#696969");
-        m.insert("dimgrey", "#696969");
-        m.insert("dodgerblue", "#1e90ff");
-        m.insert("firebrick", "#b22222");
-        m.insert("floralwhite", "#fffaf0");
-        m.insert("forestgreen", "#228b22");
-        m.insert("fuchsia", "#ff00ff");
-        m.insert("gainsboro", "#dcdcdc");
-        m.insert("ghostwhite", "#f8f8ff");
-        m.insert("gold", "#ffd700");
-        m.insert("goldenrod", "#daa520");
-        m.insert("gray", "#808080");
-        m.insert("green", "#008000");
-        m.insert("greenyellow", "#adff2f");
-        m.insert("grey", "#808080");
-        m.insert("honeydew", "#f0fff0");
-        m.insert("hotpink", "#ff69b4");
-        m.insert("indianred", "#cd5c5c");
-        m.insert("indigo", "#4b0082");
-        m.insert("ivory", "#fffff0");
-        m.insert("khaki", "#f0e68c");
-        m.insert("laserlemon", "#ffff54");
-        m.insert("lavender", "#e6e6fa");
-        m.insert("lavenderblush", "#fff0f5");
-        m.insert("lawngreen", "#7cfc00");
-        m.insert("lemonchiffon", "#fffacd");
-        m.insert("lightblue", "#add8e6");
-        m.insert("lightcoral", "#f08080");
-        m.insert("lightcyan", "#e0ffff");
-        m.insert("lightgoldenrod", "#fafad2");
-        m.insert("lightgoldenrodyellow", "#fafad2");
-        m.insert("lightgray", "#d3d3d3");
-        m.insert("lightgreen", "#90ee90");
-        m.insert("lightgrey", "#d3d3d3");
-        m.insert("lightpink", "#ffb6c1");
-        m.insert("lightsalmon", "#ffa07a");
-        m.insert("lightseagreen", "#20b2aa");
-        m.insert("lightskyblue", "#87cefa");
-        m.insert("lightslategray", "#778899");
-        m.insert("lightslategrey", "#778899");
-        m.insert("lightsteelblue", "#b0c4de");
-        m.insert("lightyellow", "#ffffe0");
-        m.insert("lime", "#00ff00");
-        m.insert("limegreen", "#32cd32");
-        m.insert("linen", "#faf0e6");
-        m.insert("magenta", "#ff00ff");
-        m.insert("maroon", "#800000");
-        m.insert("maroon2", "#7f0000");
-        m.insert("maroon3", "#b03060");
-        m.insert("mediumaquamarine", "#66cdaa");
-        m.insert("mediumblue", "#0000cd");
-        m.insert("mediumorchid", "#ba55d3");
-        m.insert("mediumpurple", "#9370db");
-        m.insert("mediumseagreen", "#3cb371");
-        m.insert("mediumslateblue", "#7b68ee");
-        m.insert("mediumspringgreen", "#00fa9a");
-        m.insert("mediumturquoise", "#48d1cc");
-        m.insert("mediumvioletred", "#c71585");
-        m.insert("midnightblue", "#191970");
-        m.insert("mintcream", "#f5fffa");
-        m.insert("mistyrose", "#ffe4e1");
-        m.insert("moccasin", "#ffe4b5");
-        m.insert("navajowhite", "#ffdead");
-        m.insert("navy", "#000080");
-        m.insert("oldlace", "#fdf5e6");
-        m.insert("olive", "#808000");
-        m.insert("olivedrab", "#6b8e23");
-        m.insert("orange", "#ffa500");
-        m.insert("orangered", "#ff4500");
-        m.insert("orchid", "#da70d6");
-        m.insert("palegoldenrod", "#eee8aa");
-        m.insert("palegreen", "#98fb98");
-        m.insert("paleturquoise", "#afeeee");
-        m.insert("palevioletred", "#db7093");
-        m.insert("papayawhip", "#ffefd5");
-        m.insert("peachpuff", "#ffdab9");
-        m.insert("peru", "#cd853f");
-        m.insert("pink", "#ffc0cb");
-        m.insert("plum", "#dda0dd");
-        m.insert("powderblue", "#b0e0e6");
-        m.insert("purple", "#800080");
-        m.insert("purple2", "#7f007f");
-        m.insert("purple3", "#a020f0");
-        m.insert("rebeccapurple", "#663399");
-        m.insert("red", "#ff0000");
-        m.insert("rosybrown", "#bc8f8f");
-        m.insert("royalblue", "#4169e1");
-        m.insert("saddlebrown", "#8b4513");
-        m.insert("salmon", "#fa8072");
-        m.insert("sandybrown", "#f4a460");
-        m.insert("seagreen", "#2e8b57");
-        m.insert("seashell", "#fff5ee");
-        m.insert("sienna", "#a0522d");
-        m.insert("silver", "#c0c0c0");
-        m.insert("skyblue", "#87ceeb");
-        m.insert("slateblue", "#6a5acd");
-        m.insert("slategray", "#708090");
-        m.insert("slategrey", "#708090");
-        m.insert("snow", "#fffafa");
-        m.insert("springgreen", "#00ff7f");
-        m.insert("steelblue", "#4682b4");
-        m.insert("tan", "#d2b48c");
-        m.insert("teal", "#008080");
-        m.insert("thistle", "#d8bfd8");
-        m.insert("tomato", "#ff6347");
-        m.insert("turquoise", "#40e0d0");
-        m.insert("violet", "#ee82ee");
-        m.insert("wheat", "#f5deb3");
-        m.insert("white", "#ffffff");
-        m.insert("whitesmoke", "#f5f5f5");
-        m.insert("yellow", "#ffff00");
-        m.insert("yellowgreen", "#9acd32");
-        m
-    };
-}
+/// The X11 color names keyed by RGB number, sorted by number then name
+pub static W3CX11_BY_HEX: [(u32, &str); 154] = [
+    (0x000000, "black"),
+    (0x000080, "navy"),
+    (0x00008b, "darkblue"),
+    (0x0000cd, "mediumblue"),
+    (0x0000ff, "blue"),
+    (0x006400, "darkgreen"),
+    (0x008000, "green"),
+    (0x008080, "teal"),
+    (0x008b8b, "darkcyan"),
+    (0x00bfff, "deepskyblue"),
+    (0x00ced1, "darkturquoise"),
+    (0x00fa9a, "mediumspringgreen"),
+    (0x00ff00, "lime"),
+    (0x00ff7f, "springgreen"),
+    (0x00ffff, "aqua"),
+    (0x00ffff, "cyan"),
+    (0x191970, "midnightblue"),
+    (0x1e90ff, "dodgerblue"),
+    (0x20b2aa, "lightseagreen"),
+    (0x228b22, "forestgreen"),
+    (0x2e8b57, "seagreen"),
+    (0x2f4f4f, "darkslategray"),
+    (0x2f4f4f, "darkslategrey"),
+    (0x32cd32, "limegreen"),
+    (0x3cb371, "mediumseagreen"),
+    (0x40e0d0, "turquoise"),
+    (0x4169e1, "royalblue"),
+    (0x4682b4, "steelblue"),
+    (0x483d8b, "darkslateblue"),
+    (0x48d1cc, "mediumturquoise"),
+    (0x4b0082, "indigo"),
+    (0x556b2f, "darkolivegreen"),
+    (0x5f9ea0, "cadetblue"),
+    (0x6495ed, "cornflowerblue"),
+    (0x663399, "rebeccapurple"),
+    (0x66cdaa, "mediumaquamarine"),
+    (0x696969, "dimgray"),
+    (0x696969, "dimgrey"),
+    (0x6a5acd, "slateblue"),
+    (0x6b8e23, "olivedrab"),
+    (0x708090, "slategray"),
+    (0x708090, "slategrey"),
+    (0x778899, "lightslategray"),
+    (0x778899, "lightslategrey"),
+    (0x7b68ee, "mediumslateblue"),
+    (0x7cfc00, "lawngreen"),
+    (0x7f0000, "maroon2"),
+    (0x7f007f, "purple2"),
+    (0x7fff00, "chartreuse"),
+    (0x7fffd4, "aquamarine"),
+    (0x800000, "maroon"),
+    (0x800080, "purple"),
+    (0x808000, "olive"),
+    (0x808080, "gray"),
+    (0x808080, "grey"),
+    (0x87ceeb, "skyblue"),
+    (0x87cefa, "lightskyblue"),
+    (0x8a2be2, "blueviolet"),
+    (0x8b0000, "darkred"),
+    (0x8b008b, "darkmagenta"),
+    (0x8b4513, "saddlebrown"),
+    (0x8fbc8f, "darkseagreen"),
+    (0x90ee90, "lightgreen"),
+    (0x9370db, "mediumpurple"),
+    (0x9400d3, "darkviolet"),
+    (0x98fb98, "palegreen"),
+    (0x9932cc, "darkorchid"),
+    (0x9acd32, "yellowgreen"),
+    (0xa020f0, "purple3"),
+    (0xa0522d, "sienna"),
+    (0xa52a2a, "brown"),
+    (0xa9a9a9, "darkgray"),
+    (0xa9a9a9, "darkgrey"),
+    (0xadd8e6, "lightblue"),
+    (0xadff2f, "greenyellow"),
+    (0xafeeee, "paleturquoise"),
+    (0xb03060, "maroon3"),
+    (0xb0c4de, "lightsteelblue"),
+    (0xb0e0e6, "powderblue"),
+    (0xb22222, "firebrick"),
+    (0xb8860b, "darkgoldenrod"),
+    (0xba55d3, "mediumorchid"),
+    (0xbc8f8f, "rosybrown"),
+    (0xbdb76b, "darkkhaki"),
+    (0xc0c0c0, "silver"),
+    (0xc71585, "mediumvioletred"),
+    (0xcd5c5c, "indianred"),
+    (0xcd853f, "peru"),
+    (0xd2691e, "chocolate"),
+    (0xd2b48c, "tan"),
+    (0xd3d3d3, "lightgray"),
+    (0xd3d3d3, "lightgrey"),
+    (0xd8bfd8, "thistle"),
+    (0xda70d6, "orchid"),
+    (0xdaa520, "goldenrod"),
+    (0xdb7093, "palevioletred"),
+    (0xdc143c, "crimson"),
+    (0xdcdcdc, "gainsboro"),
+    (0xdda0dd, "plum"),
+    (0xdeb887, "burlywood"),
+    (0xe0ffff, "lightcyan"),
+    (0xe6e6fa, "lavender"),
+    (0xe9967a, "darksalmon"),
+    (0xee82ee, "violet"),
+    (0xeee8aa, "palegoldenrod"),
+    (0xf08080, "lightcoral"),
+    (0xf0e68c, "khaki"),
+    (0xf0f8ff, "aliceblue"),
+    (0xf0fff0, "honeydew"),
+    (0xf0ffff, "azure"),
+    (0xf4a460, "sandybrown"),
+    (0xf5deb3, "wheat"),
+    (0xf5f5dc, "beige"),
+    (0xf5f5f5, "whitesmoke"),
+    (0xf5fffa, "mintcream"),
+    (0xf8f8ff, "ghostwhite"),
+    (0xfa8072, "salmon"),
+    (0xfaebd7, "antiquewhite"),
+    (0xfaf0e6, "linen"),
+    (0xfafad2, "lightgoldenrod"),
+    (0xfafad2, "lightgoldenrodyellow"),
+    (0xfdf5e6, "oldlace"),
+    (0xff0000, "red"),
+    (0xff00ff, "fuchsia"),
+    (0xff00ff, "magenta"),
+    (0xff1493, "deeppink"),
+    (0xff4500, "orangered"),
+    (0xff6347, "tomato"),
+    (0xff69b4, "hotpink"),
+    (0xff7f50, "coral"),
+    (0xff8c00, "darkorange"),
+    (0xffa07a, "lightsalmon"),
+    (0xffa500, "orange"),
+    (0xffb6c1, "lightpink"),
+    (0xffc0cb, "pink"),
+    (0xffd700, "gold"),
+    (0xffdab9, "peachpuff"),
+    (0xffdead, "navajowhite"),
+    (0xffe4b5, "moccasin"),
+    (0xffe4c4, "bisque"),
+    (0xffe4e1, "mistyrose"),
+    (0xffebcd, "blanchedalmond"),
+    (0xffefd5, "papayawhip"),
+    (0xfff0f5, "lavenderblush"),
+    (0xfff5ee, "seashell"),
+    (0xfff8dc, "cornsilk"),
+    (0xfffacd, "lemonchiffon"),
+    (0xfffaf0, "floralwhite"),
+    (0xfffafa, "snow"),
+    (0xffff00, "yellow"),
+    (0xffff54, "laserlemon"),
+    (0xffffe0, "lightyellow"),
+    (0xfffff0, "ivory"),
+    (0xffffff, "white"),
+];
+
+/// The X11 colors in CIE Lab (D65), sorted by name
+pub static W3CX11_LAB: [(&str, (f64, f64, f64)); 154] = [
+    (
+        "aliceblue",
+        (97.17864982306108, -1.3486158598345344, -4.262854157273543),
+    ),
+    (
+        "antiquewhite",
+        (93.73133223938991, 1.8386769861942764, 11.526165646584307),
+    ),
+    (
+        "aqua",
+        (91.11321981275862, -48.08752805875827, -14.131186091754412),
+    ),
+    (
+        "aquamarine",
+        (92.03397884634897, -45.52453781567362, 9.718128684127159),
+    ),
+    (
+        "azure",
+        (98.93241521239445, -4.880395251172565, -1.6882753195310851),
+    ),
+    (
+        "beige",
+        (95.94908856266986, -4.192868939387306, 12.048995703858001),
+    ),
+    (
+        "bisque",
+        (92.01343089829784, 4.43087305746287, 19.01200714641311),
+    ),
+    ("black", (0.0, 0.0, 0.0)),
+    (
+        "blanchedalmond",
+        (93.92026167090177, 2.1301625656336975, 17.02614590139082),
+    ),
+    (
+        "blue",
+        (32.29701093285073, 79.18751984512221, -107.8601617541481),
+    ),
+    (
+        "blueviolet",
+        (42.18785272476706, 69.84479987380188, -74.76337422288755),
+    ),
+    (
+        "brown",
+        (37.52650524281069, 49.69034644081097, 30.543166542619616),
+    ),
+    (
+        "burlywood",
+        (77.01835891068221, 7.049925060326368, 30.018853082835584),
+    ),
+    (
+        "cadetblue",
+        (61.15314791154566, -19.67944384022957, -7.420779647830189),
+    ),
+    (
+        "chartreuse",
+        (89.87270793937743, -68.06612889835428, 85.77999312394681),
+    ),
+    (
+        "chocolate",
+        (55.9900594998559, 37.05265126222618, 56.7407095280427),
+    ),
+    (
+        "coral",
+        (67.29503683145923, 45.35429004406022, 47.49337281545699),
+    ),
+    (
+        "cornflowerblue",
+        (61.92593782647535, 9.332998515857781, -49.29810509017016),
+    ),
+    (
+        "cornsilk",
+        (97.45567595155853, -2.2176727901830895, 14.29352498520975),
+    ),
+    (
+        "crimson",
+        (47.0364457337184, 70.92110990013839, 33.59967220947146),
+    ),
+    (
+        "cyan",
+        (91.11321981275862, -48.08752805875827, -14.131186091754412),
+    ),
+    (
+        "darkblue",
+        (14.753606410438852, 50.42344797117157, -68.68104045952701),
+    ),
+    (
+        "darkcyan",
+        (52.20541768219037, -30.620216088033057, -8.998174561624595),
+    ),
+    (
+        "darkgoldenrod",
+        (59.220700501110144, 9.864750526224153, 62.73045915592365),
+    ),
+    (
+        "darkgray",
+        (
+            69.23779844683675,
+            -1.2246809288907201e-5,
+            4.8987237155628804e-6,
+        ),
+    ),
+    (
+        "darkgreen",
+        (36.20235570120914, -43.369671367899905, 41.85827442714117),
+    ),
+    (
+        "darkgrey",
+        (
+            69.23779844683675,
+            -1.2246809288907201e-5,
+            4.8987237155628804e-6,
+        ),
+    ),
+    (
+        "darkkhaki",
+        (73.38198084806379, -8.787701661144954, 39.29167247855201),
+    ),
+    (
+        "darkmagenta",
+        (32.600208046956865, 62.55168395419478, -38.73086054019142),
+    ),
+    (
+        "darkolivegreen",
+        (42.23385417080879, -18.82782770862207, 30.59837289660511),
+    ),
+    (
+        "darkorange",
+        (69.48534217678309, 36.8257412136272, 75.48709853720096),
+    ),
+    (
+        "darkorchid",
+        (43.380241127805846, 65.15353302753773, -60.097712889134925),
+    ),
+    (
+        "darkred",
+        (28.08977055595797, 50.999677439595466, 41.29078998628349),
+    ),
+    (
+        "darksalmon",
+        (69.85628507483399, 28.17423012696374, 27.71170960414253),
+    ),
+    (
+        "darkseagreen",
+        (72.08667670093459, -23.819555602939914, 18.03775247250283),
+    ),
+    (
+        "darkslateblue",
+        (30.828347417822897, 26.05097422743419, -42.082532834089),
+    ),
+    (
+        "darkslategray",
+        (31.25523491020497, -11.719854659342111, -3.723639950456592),
+    ),
+    (
+        "darkslategrey",
+        (31.25523491020497, -11.719854659342111, -3.723639950456592),
+    ),
+    (
+        "darkturquoise",
+        (75.2902383626794, -40.04327241365352, -13.513332720755834),
+    ),
+    (
+        "darkviolet",
+        (39.57976071046602, 76.3219740026932, -70.3663642234947),
+    ),
+    (
+        "deeppink",
+        (55.96083930767105, 84.53868716438068, -5.700009514268456),
+    ),
+    (
+        "deepskyblue",
+        (72.54592077051686, -17.658557723658706, -42.5411700324019),
+    ),
+    (
+        "dimgray",
+        (
+            44.413562161601284,
+            -8.680108964931321e-6,
+            3.4720435859725285e-6,
+        ),
+    ),
+    (
+        "dimgrey",
+        (
+            44.413562161601284,
+            -8.680108964931321e-6,
+            3.4720435859725285e-6,
+        ),
+    ),
+    (
+        "dodgerblue",
+        (59.37830246439867, 9.95758927927487, -63.38784104988957),
+    ),
+    (
+        "firebrick",
+        (39.11793223831643, 55.91677162395209, 37.64905098386787),
+    ),
+    (
+        "floralwhite",
+        (98.4016480104955, -0.03654042369410915, 5.376192798848667),
+    ),
+    (
+        "forestgreen",
+        (50.59307310556156, -49.585382632805654, 45.01596445170293),
+    ),
+    (
+        "fuchsia",
+        (60.32421212836874, 98.23431188800402, -60.82489220885006),
+    ),
+    (
+        "gainsboro",
+        (
+            87.76089156874731,
+            -1.4908173084915433e-5,
+            5.9632692117617125e-6,
+        ),
+    ),
+    (
+        "ghostwhite",
+        (97.75721564588999, 1.247116402219639, -3.345466101118233),
+    ),
+    (
+        "gold",
+        (86.93056964872586, -1.9237487045783985, 87.13203644896554),
+    ),
+    (
+        "goldenrod",
+        (70.8179749045359, 8.524095050159609, 68.76186169872271),
+    ),
+    (
+        "gray",
+        (
+            53.585015771669404,
+            -9.997846384113274e-6,
+            3.9991385536453095e-6,
+        ),
+    ),
+    (
+        "green",
+        (46.22743146876261, -51.69849552989111, 49.8968460010561),
+    ),
+    (
+        "greenyellow",
+        (91.95682614711973, -52.48084686116411, 81.86448096951315),
+    ),
+    (
+        "grey",
+        (
+            53.585015771669404,
+            -9.997846384113274e-6,
+            3.9991385536453095e-6,
+        ),
+    ),
+    (
+        "honeydew",
+        (98.56556109114874, -7.564939131992244, 5.475317075314501),
+    ),
+    (
+        "hotpink",
+        (65.48615893257741, 64.23845664189554, -10.64635269010239),
+    ),
+    (
+        "indianred",
+        (53.39511539368607, 44.82828427031427, 22.117128186598144),
+    ),
+    (
+        "indigo",
+        (20.469442937165013, 51.685573451477175, -53.31262311769456),
+    ),
+    (
+        "ivory",
+        (99.63990282276274, -2.551393440697103, 7.162635096575398),
+    ),
+    (
+        "khaki",
+        (90.32817677781551, -9.009831825025017, 44.979271409297915),
+    ),
+    (
+        "laserlemon",
+        (97.39875875593755, -19.466620653013354, 77.66429617201052),
+    ),
+    (
+        "lavender",
+        (91.82750990881723, 3.7078388829650533, -9.661308832101723),
+    ),
+    (
+        "lavenderblush",
+        (96.06872830620557, 5.887335539538297, -0.5936910923768357),
+    ),
+    (
+        "lawngreen",
+        (88.87648166105618, -67.85606877030042, 84.95247951662427),
+    ),
+    (
+        "lemonchiffon",
+        (97.64817944823999, -5.426768564686046, 22.233845208771964),
+    ),
+    (
+        "lightblue",
+        (83.81294620155342, -10.891784263162785, -11.476672117761332),
+    ),
+    (
+        "lightcoral",
+        (66.15684757284254, 42.80991767479375, 19.556811908356785),
+    ),
+    (
+        "lightcyan",
+        (97.8674067949278, -9.944510013456753, -3.3750461176262014),
+    ),
+    (
+        "lightgoldenrod",
+        (97.36911644222596, -6.481069629579395, 19.237243925687288),
+    ),
+    (
+        "lightgoldenrodyellow",
+        (97.36911644222596, -6.481069629579395, 19.237243925687288),
+    ),
+    (
+        "lightgray",
+        (
+            84.55612008823094,
+            -1.4447717466836707e-5,
+            5.779086986734683e-6,
+        ),
+    ),
+    (
+        "lightgreen",
+        (86.5482148523122, -46.3279548093577, 36.94910115933907),
+    ),
+    (
+        "lightgrey",
+        (
+            84.55612008823094,
+            -1.4447717466836707e-5,
+            5.779086986734683e-6,
+        ),
+    ),
+    (
+        "lightpink",
+        (81.05459120164177, 27.962641144971933, 5.03595185691168),
+    ),
+    (
+        "lightsalmon",
+        (74.70611833119872, 31.477523633087866, 34.548660195899195),
+    ),
+    (
+        "lightseagreen",
+        (65.78533251048465, -37.51394762192728, -6.330951041241795),
+    ),
+    (
+        "lightskyblue",
+        (79.72300339765326, -10.83112584039464, -28.501786742100688),
+    ),
+    (
+        "lightslategray",
+        (55.916717227912756, -2.2476866618177382, -11.10796738045301),
+    ),
+    (
+        "lightslategrey",
+        (55.916717227912756, -2.2476866618177382, -11.10796738045301),
+    ),
+    (
+        "lightsteelblue",
+        (78.45157936968134, -1.2815839134120433, -15.210996213841522),
+    ),
+    (
+        "lightyellow",
+        (99.28508946335137, -5.107293032951821, 14.837756269209844),
+    ),
+    (
+        "lime",
+        (87.73472235279792, -86.1827164205346, 83.17932050269782),
+    ),
+    (
+        "limegreen",
+        (72.60670843346618, -67.12554740055154, 61.43722175462833),
+    ),
+    (
+        "linen",
+        (95.31154768412136, 1.6774446186216818, 6.0221196609898),
+    ),
+    (
+        "magenta",
+        (60.32421212836874, 98.23431188800402, -60.82489220885006),
+    ),
+    (
+        "maroon",
+        (25.53553096346318, 48.045128262358375, 38.05732084720349),
+    ),
+    (
+        "maroon2",
+        (25.301403963988996, 47.77430804028601, 37.754031358938064),
+    ),
+    (
+        "maroon3",
+        (41.51851251142242, 54.70333559721172, 2.4684642364683373),
+    ),
+    (
+        "mediumaquamarine",
+        (75.69130098624734, -38.335641262158546, 8.30799094776249),
+    ),
+    (
+        "mediumblue",
+        (24.971427211092923, 67.17653210200228, -91.50017114787823),
+    ),
+    (
+        "mediumorchid",
+        (53.643760287459386, 59.06040502949056, -47.402328847545185),
+    ),
+    (
+        "mediumpurple",
+        (54.97480369137361, 36.79775930407231, -50.08946672652499),
+    ),
+    (
+        "mediumseagreen",
+        (65.27164698427852, -48.218190113850035, 24.290181695446723),
+    ),
+    (
+        "mediumslateblue",
+        (52.15598676126709, 41.0683862091844, -65.39619057229163),
+    ),
+    (
+        "mediumspringgreen",
+        (87.33852804427205, -70.68646790984329, 32.46283650557118),
+    ),
+    (
+        "mediumturquoise",
+        (76.88100505283627, -37.36018697309623, -8.354797318364838),
+    ),
+    (
+        "mediumvioletred",
+        (44.7666156556429, 70.99211491682411, -15.16922381396897),
+    ),
+    (
+        "midnightblue",
+        (15.857600599624746, 31.713343200450357, -49.574634483539604),
+    ),
+    (
+        "mintcream",
+        (99.15639517521527, -4.1629381247078046, 1.2463814805574014),
+    ),
+    (
+        "mistyrose",
+        (92.65633786068564, 8.747082134450634, 4.835717904967218),
+    ),
+    (
+        "moccasin",
+        (91.72317744746022, 2.4393469358685027, 26.359832514614844),
+    ),
+    (
+        "navajowhite",
+        (90.10135206616187, 4.510130944059054, 28.272188134629104),
+    ),
+    (
+        "navy",
+        (12.971966857430804, 47.50228132471322, -64.70216212599588),
+    ),
+    (
+        "oldlace",
+        (96.78000571514856, 0.17095577349707236, 8.166223847295306),
+    ),
+    (
+        "olive",
+        (51.86894337734398, -12.929464306735083, 56.67457900899427),
+    ),
+    (
+        "olivedrab",
+        (54.65049965773851, -28.22177708319551, 49.690724638504676),
+    ),
+    (
+        "orange",
+        (74.93565017306031, 23.933170767745093, 78.94977540341804),
+    ),
+    (
+        "orangered",
+        (57.581726990370356, 67.78274324680167, 68.95861265241977),
+    ),
+    (
+        "orchid",
+        (62.80321256891452, 55.282360871189816, -34.40444392828616),
+    ),
+    (
+        "palegoldenrod",
+        (91.14101083349169, -7.349102825780662, 30.97133777393408),
+    ),
+    (
+        "palegreen",
+        (90.74961847330792, -48.296798886442396, 38.527726143293314),
+    ),
+    (
+        "paleturquoise",
+        (90.05999059595938, -19.63837993814571, -6.399936733685507),
+    ),
+    (
+        "palevioletred",
+        (60.568036293191966, 45.51905732853123, 0.4022608869880262),
+    ),
+    (
+        "papayawhip",
+        (95.07607393817332, 1.270732069875935, 14.525435575274814),
+    ),
+    (
+        "peachpuff",
+        (89.35003074318084, 8.085208765076068, 21.02246563599377),
+    ),
+    (
+        "peru",
+        (61.75442209392598, 21.395538148832717, 47.918328707637045),
+    ),
+    (
+        "pink",
+        (83.58651829609448, 24.143630849775167, 3.3258937908858366),
+    ),
+    (
+        "plum",
+        (73.3739042969524, 32.530876959829754, -21.985652073908966),
+    ),
+    (
+        "powderblue",
+        (86.13240587199145, -14.092919363306034, -8.007606810319956),
+    ),
+    (
+        "purple",
+        (29.784666617920195, 58.92789581199415, -36.48707709120311),
+    ),
+    (
+        "purple2",
+        (29.526588139842985, 58.595731731946074, -36.28140715799676),
+    ),
+    (
+        "purple3",
+        (45.356542789439416, 78.7350266652958, -77.39344408640318),
+    ),
+    (
+        "rebeccapurple",
+        (32.90246766737562, 42.88307446031139, -47.148633770801105),
+    ),
+    (
+        "red",
+        (53.24079414130722, 80.09245959641109, 67.20319651585301),
+    ),
+    (
+        "rosybrown",
+        (63.60740633702609, 17.01266926931283, 6.609691877882717),
+    ),
+    (
+        "royalblue",
+        (47.83007360562802, 26.263097389935485, -65.26366492790537),
+    ),
+    (
+        "saddlebrown",
+        (37.469798326367545, 26.44258449777673, 40.983818845124645),
+    ),
+    (
+        "salmon",
+        (67.26409284042029, 45.22646860902269, 29.094269715625142),
+    ),
+    (
+        "sandybrown",
+        (73.95445231767795, 23.026975825974073, 46.791245442927234),
+    ),
+    (
+        "seagreen",
+        (51.533898679419906, -39.71533993687126, 20.052184958342355),
+    ),
+    (
+        "seashell",
+        (97.12143678944318, 2.162200636999567, 4.554110971324388),
+    ),
+    (
+        "sienna",
+        (43.79918613858127, 29.32232425471787, 35.638442812588856),
+    ),
+    (
+        "silver",
+        (
+            77.7043667134314,
+            -1.3463270165381402e-5,
+            5.385308066152561e-6,
+        ),
+    ),
+    (
+        "skyblue",
+        (79.20710283748852, -14.838968916051021, -21.276506647850567),
+    ),
+    (
+        "slateblue",
+        (45.33597235033841, 36.0394540569553, -57.77192349093172),
+    ),
+    (
+        "slategray",
+        (52.83565639102366, -2.1427989072161924, -10.570981702672455),
+    ),
+    (
+        "slategrey",
+        (52.83565639102366, -2.1427989072161924, -10.570981702672455),
+    ),
+    (
+        "snow",
+        (98.64389478856151, 1.656743701996699, 0.5874662151005294),
+    ),
+    (
+        "springgreen",
+        (88.4701235760925, -76.90174544494771, 47.0277827647632),
+    ),
+    (
+        "steelblue",
+        (52.46551718768575, -4.0774710123572255, -32.19186122981343),
+    ),
+    (
+        "tan",
+        (74.97571633726538, 5.021257635206167, 24.42813569728395),
+    ),
+    (
+        "teal",
+        (48.254093461861615, -28.846304196984917, -8.476885985257177),
+    ),
+    (
+        "thistle",
+        (80.07779499077584, 13.217587590566959, -9.228882166481146),
+    ),
+    (
+        "tomato",
+        (62.206929262837946, 57.851264102126876, 46.419810975648716),
+    ),
+    (
+        "turquoise",
+        (81.26443338399086, -44.08188213540165, -4.028385738801887),
+    ),
+    (
+        "violet",
+        (69.6957685007, 56.356649735983254, -36.8098649331243),
+    ),
+    (
+        "wheat",
+        (89.35163634614379, 1.5115244795133598, 24.007857146563705),
+    ),
+    (
+        "white",
+        (
+            100.00000386666655,
+            -1.6666666158293708e-5,
+            6.666666463317483e-6,
+        ),
+    ),
+    (
+        "whitesmoke",
+        (
+            96.53749336548567,
+            -1.616917905122861e-5,
+            6.467671598286984e-6,
+        ),
+    ),
+    (
+        "yellow",
+        (97.1392672243063, -21.553748216377066, 94.47797505367026),
+    ),
+    (
+        "yellowgreen",
+        (76.5348082120575, -37.98791296907117, 66.58562620666609),
+    ),
+];
@@ -0,0 +1,272 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::utils::distance::ciede2000;
+use crate::{Color, CvdKind, RandomColor};
+
+// Number of sampled candidates, per color of the palette and at the minimum
+static CANDIDATES_PER_COLOR: usize = 50;
+static MIN_CANDIDATES: usize = 500;
+static MAX_ITERATIONS: usize = 20;
+
+/// A generator of maximally distinct colors, e.g. for the series of a chart.
+///
+/// Like [iwanthue](https://medialab.github.io/iwanthue/), candidates are
+/// sampled within the constraints, clustered with k-means in CIE Lab, then the
+/// palette is refined to maximize the smallest CIEDE2000 difference between
+/// its colors.
+///
+/// ```
+/// use chroma_rust::{delta_e, DistinctColors, RandomColor};
+///
+/// let colors = DistinctColors::new(6)
+///     .constraints(RandomColor::new().lightness(0.5, 0.85).chroma(0.08, 0.4))
+///     .seed(1)
+///     .generate();
+/// assert_eq!(colors.len(), 6);
+/// for (i, a) in colors.iter().enumerate() {
+///     for b in &colors[i + 1..] {
+///         assert!(delta_e(a, b) > 15.);
+///     }
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct DistinctColors {
+    count: usize,
+    constraints: RandomColor,
+    cvd_safe: bool,
+    seed: Option<u64>,
+}
+
+impl DistinctColors {
+    pub fn new(count: usize) -> DistinctColors {
+        DistinctColors {
+            count,
+            constraints: RandomColor::new(),
+            cvd_safe: false,
+            seed: None,
+        }
+    }
+
+    /// Constrain the colors of the palette, the seed of `constraints` is ignored.
+    pub fn constraints(mut self, constraints: RandomColor) -> DistinctColors {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Keep the colors distinct for people with protanopia, deuteranopia or tritanopia.
+    pub fn cvd_safe(mut self, cvd_safe: bool) -> DistinctColors {
+        self.cvd_safe = cvd_safe;
+        self
+    }
+
+    /// Make the output reproducible.
+    pub fn seed(mut self, seed: u64) -> DistinctColors {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Generate the palette, from the seed if any.
+    pub fn generate(&self) -> Vec<Color> {
+        match self.seed {
+            Some(seed) => self.generate_with(&mut StdRng::seed_from_u64(seed)),
+            None => self.generate_with(&mut rand::thread_rng()),
+        }
+    }
+
+    /// Generate the palette with the given random number generator, ignoring the seed.
+    pub fn generate_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Color> {
+        if self.count == 0 {
+            return vec![];
+        }
+        let total = (self.count * CANDIDATES_PER_COLOR).max(MIN_CANDIDATES);
+        let candidates: Vec<Candidate> = (0..total)
+            .map(|_| Candidate::new(self.constraints.generate_with(rng), self.cvd_safe))
+            .collect();
+
+        let mut chosen = self.farthest_points(&candidates, rng);
+        self.k_means(&candidates, &mut chosen);
+        self.refine(&candidates, &mut chosen);
+        chosen.into_iter().map(|i| candidates[i].color).collect()
+    }
+
+    /// Choose a random candidate, then repeatedly the farthest from the chosen ones.
+    fn farthest_points<R: Rng + ?Sized>(
+        &self,
+        candidates: &[Candidate],
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut chosen = vec![rng.gen_range(0..candidates.len())];
+        let mut nearest: Vec<f64> = candidates
+            .iter()
+            .map(|c| c.distance(&candidates[chosen[0]]))
+            .collect();
+        while chosen.len() < self.count.min(candidates.len()) {
+            let next = argmax(&nearest);
+            chosen.push(next);
+            for (d, c) in nearest.iter_mut().zip(candidates) {
+                *d = d.min(c.distance(&candidates[next]));
+            }
+        }
+        chosen
+    }
+
+    /// Move each chosen color to the candidate the nearest to the mean of its cluster.
+    fn k_means(&self, candidates: &[Candidate], chosen: &mut [usize]) {
+        for _ in 0..MAX_ITERATIONS {
+            let mut sums = vec![(0., 0., 0., 0); chosen.len()];
+            for candidate in candidates {
+                let distances: Vec<f64> = chosen
+                    .iter()
+                    .map(|&i| -candidate.distance(&candidates[i]))
+                    .collect();
+                let sum = &mut sums[argmax(&distances)];
+                let (l, a, b) = candidate.lab;
+                *sum = (sum.0 + l, sum.1 + a, sum.2 + b, sum.3 + 1);
+            }
+
+            let mut changed = false;
+            for (center, (l, a, b, n)) in chosen.iter_mut().zip(sums) {
+                if n == 0 {
+                    continue;
+                }
+                let mean = (l / n as f64, a / n as f64, b / n as f64);
+                let distances: Vec<f64> =
+                    candidates.iter().map(|c| -ciede2000(c.lab, mean)).collect();
+                let nearest = argmax(&distances);
+                changed |= nearest != *center;
+                *center = nearest;
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Replace each chosen color by the candidate the farthest from the others,
+    /// pushing the colors away from each other.
+    fn refine(&self, candidates: &[Candidate], chosen: &mut [usize]) {
+        for _ in 0..MAX_ITERATIONS {
+            let mut changed = false;
+            for k in 0..chosen.len() {
+                let nearest = |c: &Candidate| {
+                    chosen
+                        .iter()
+                        .enumerate()
+                        .filter(|&(j, _)| j != k)
+                        .map(|(_, &i)| c.distance(&candidates[i]))
+                        .fold(f64::MAX, f64::min)
+                };
+                let current = nearest(&candidates[chosen[k]]);
+                let scores: Vec<f64> = candidates.iter().map(nearest).collect();
+                let best = argmax(&scores);
+                if scores[best] > current {
+                    chosen[k] = best;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+}
+
+/// A sampled color, with its CIE Lab coordinates as seen with normal vision
+/// and with each color vision deficiency.
+struct Candidate {
+    color: Color,
+    lab: (f64, f64, f64),
+    cvd_labs: Vec<(f64, f64, f64)>,
+}
+
+impl Candidate {
+    fn new(color: Color, cvd_safe: bool) -> Candidate {
+        let cvd_labs = if cvd_safe {
+            CvdKind::DICHROMACIES
+                .iter()
+                .map(|&kind| color.simulate_cvd(kind, 1.).lab())
+                .collect()
+        } else {
+            vec![]
+        };
+        Candidate {
+            lab: color.lab(),
+            color,
+            cvd_labs,
+        }
+    }
+
+    /// The smallest CIEDE2000 difference with normal vision or any deficiency.
+    fn distance(&self, other: &Candidate) -> f64 {
+        self.cvd_labs
+            .iter()
+            .zip(&other.cvd_labs)
+            .map(|(a, b)| ciede2000(*a, *b))
+            .fold(ciede2000(self.lab, other.lab), f64::min)
+    }
+}
+
+fn argmax(values: &[f64]) -> usize {
+    values
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(i, _)| i)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delta_e;
+
+    fn min_distance(colors: &[Color]) -> f64 {
+        let mut min = f64::MAX;
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                min = min.min(delta_e(a, b));
+            }
+        }
+        min
+    }
+
+    #[test]
+    fn test_distinct_colors() {
+        let colors = DistinctColors::new(8).seed(2).generate();
+        assert_eq!(colors.len(), 8);
+        assert_eq!(colors, DistinctColors::new(8).seed(2).generate());
+
+        // far more distinct than random colors
+        let random = RandomColor::new().seed(2).generate_many(8);
+        assert!(min_distance(&colors) > min_distance(&random));
+        assert!(min_distance(&colors) > 20.);
+
+        assert!(DistinctColors::new(0).generate().is_empty());
+    }
+
+    #[test]
+    fn test_distinct_colors_constraints() {
+        let constraints = RandomColor::new().hue(180., 300.).lightness(0.4, 0.8);
+        let colors = DistinctColors::new(5)
+            .constraints(constraints)
+            .seed(3)
+            .generate();
+        for color in colors {
+            let (l, _, h) = color.oklch();
+            assert!((0.4..=0.8).contains(&l));
+            assert!((180. ..=300.).contains(&h));
+        }
+    }
+
+    #[test]
+    fn test_distinct_colors_cvd_safe() {
+        let colors = DistinctColors::new(5).cvd_safe(true).seed(4).generate();
+        let candidates: Vec<Candidate> = colors.iter().map(|c| Candidate::new(*c, true)).collect();
+        for (i, a) in candidates.iter().enumerate() {
+            for b in &candidates[i + 1..] {
+                assert!(a.distance(b) > 10.);
+            }
+        }
+    }
+}
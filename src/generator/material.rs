@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+
+use crate::Color;
+
+/// The tones of a Material Design tonal palette.
+pub static TONES: [f64; 13] = [
+    0., 10., 20., 30., 40., 50., 60., 70., 80., 90., 95., 99., 100.,
+];
+
+/// Colors of the same HCT hue and chroma, at any tone.
+///
+/// <https://m3.material.io/styles/color/system/how-the-system-works>
+///
+/// ```
+/// use chroma_rust::TonalPalette;
+///
+/// let palette = TonalPalette::new(270., 36.);
+/// assert_eq!(palette.tone(100.).hex(), "#ffffff");
+/// assert_eq!(palette.tone(0.).hex(), "#000000");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TonalPalette {
+    pub hue: f64,
+    pub chroma: f64,
+}
+
+impl TonalPalette {
+    pub fn new(hue: f64, chroma: f64) -> TonalPalette {
+        TonalPalette { hue, chroma }
+    }
+
+    /// The palette of the hue and chroma of a color.
+    pub fn from_color(color: &Color) -> TonalPalette {
+        let (hue, chroma, _) = color.hct();
+        TonalPalette { hue, chroma }
+    }
+
+    /// The color at a tone in [0, 100].
+    ///
+    /// The chroma is reduced when it's out of the sRGB gamut at that tone.
+    pub fn tone(&self, tone: f64) -> Color {
+        Color::from_hct((self.hue, self.chroma, tone))
+    }
+
+    /// The colors at each of [`TONES`].
+    pub fn tones(&self) -> Vec<Color> {
+        TONES.iter().map(|&tone| self.tone(tone)).collect()
+    }
+}
+
+/// The key tonal palettes derived from a seed color.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CorePalette {
+    pub primary: TonalPalette,
+    pub secondary: TonalPalette,
+    pub tertiary: TonalPalette,
+    pub neutral: TonalPalette,
+    pub neutral_variant: TonalPalette,
+    pub error: TonalPalette,
+}
+
+impl CorePalette {
+    pub fn new(seed: &Color) -> CorePalette {
+        let (hue, chroma, _) = seed.hct();
+        CorePalette {
+            primary: TonalPalette::new(hue, chroma.max(48.)),
+            secondary: TonalPalette::new(hue, 16.),
+            tertiary: TonalPalette::new(hue + 60., 24.),
+            neutral: TonalPalette::new(hue, 4.),
+            neutral_variant: TonalPalette::new(hue, 8.),
+            error: TonalPalette::new(25., 84.),
+        }
+    }
+}
+
+/// The color roles of a Material Design scheme.
+///
+/// ```
+/// use chroma_rust::{Color, Scheme};
+///
+/// let seed = Color::from("#4285f4");
+/// let light = Scheme::light(&seed);
+/// let dark = Scheme::dark(&seed);
+/// assert!(light.primary.hct().2 < dark.primary.hct().2);
+/// assert_eq!(light.on_primary.hex(), "#ffffff");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Scheme {
+    pub primary: Color,
+    pub on_primary: Color,
+    pub primary_container: Color,
+    pub on_primary_container: Color,
+    pub secondary: Color,
+    pub on_secondary: Color,
+    pub secondary_container: Color,
+    pub on_secondary_container: Color,
+    pub tertiary: Color,
+    pub on_tertiary: Color,
+    pub tertiary_container: Color,
+    pub on_tertiary_container: Color,
+    pub error: Color,
+    pub on_error: Color,
+    pub error_container: Color,
+    pub on_error_container: Color,
+    pub background: Color,
+    pub on_background: Color,
+    pub surface: Color,
+    pub on_surface: Color,
+    pub surface_variant: Color,
+    pub on_surface_variant: Color,
+    pub outline: Color,
+    pub outline_variant: Color,
+    pub shadow: Color,
+    pub scrim: Color,
+    pub inverse_surface: Color,
+    pub inverse_on_surface: Color,
+    pub inverse_primary: Color,
+}
+
+impl Scheme {
+    /// The light scheme of a seed color.
+    pub fn light(seed: &Color) -> Scheme {
+        Scheme::from_core(&CorePalette::new(seed), false)
+    }
+
+    /// The dark scheme of a seed color.
+    pub fn dark(seed: &Color) -> Scheme {
+        Scheme::from_core(&CorePalette::new(seed), true)
+    }
+
+    /// The light or dark scheme of the key palettes.
+    pub fn from_core(core: &CorePalette, dark: bool) -> Scheme {
+        // tones of the accent roles: the color, on, container and on container
+        let accent = if dark {
+            [80., 20., 30., 90.]
+        } else {
+            [40., 100., 90., 10.]
+        };
+        // light and dark tones of the other roles
+        let tone = |light: f64, dark_tone: f64| if dark { dark_tone } else { light };
+        let (a1, a2, a3) = (core.primary, core.secondary, core.tertiary);
+        let (n1, n2, e) = (core.neutral, core.neutral_variant, core.error);
+
+        Scheme {
+            primary: a1.tone(accent[0]),
+            on_primary: a1.tone(accent[1]),
+            primary_container: a1.tone(accent[2]),
+            on_primary_container: a1.tone(accent[3]),
+            secondary: a2.tone(accent[0]),
+            on_secondary: a2.tone(accent[1]),
+            secondary_container: a2.tone(accent[2]),
+            on_secondary_container: a2.tone(accent[3]),
+            tertiary: a3.tone(accent[0]),
+            on_tertiary: a3.tone(accent[1]),
+            tertiary_container: a3.tone(accent[2]),
+            on_tertiary_container: a3.tone(accent[3]),
+            error: e.tone(accent[0]),
+            on_error: e.tone(accent[1]),
+            error_container: e.tone(accent[2]),
+            on_error_container: e.tone(accent[3]),
+            background: n1.tone(tone(99., 10.)),
+            on_background: n1.tone(tone(10., 90.)),
+            surface: n1.tone(tone(99., 10.)),
+            on_surface: n1.tone(tone(10., 90.)),
+            surface_variant: n2.tone(tone(90., 30.)),
+            on_surface_variant: n2.tone(tone(30., 80.)),
+            outline: n2.tone(tone(50., 60.)),
+            outline_variant: n2.tone(tone(80., 30.)),
+            shadow: n1.tone(0.),
+            scrim: n1.tone(0.),
+            inverse_surface: n1.tone(tone(20., 90.)),
+            inverse_on_surface: n1.tone(tone(95., 20.)),
+            inverse_primary: a1.tone(tone(80., 40.)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tonal_palette() {
+        let palette = TonalPalette::new(270., 36.);
+        let tones = palette.tones();
+        assert_eq!(tones.len(), TONES.len());
+        assert_eq!(tones[0].hex(), "#000000");
+        assert_eq!(tones[12].hex(), "#ffffff");
+        for (color, tone) in tones.iter().zip(TONES).skip(1).take(10) {
+            let (h, c, t) = color.hct();
+            assert!((h - 270.).abs() < 2., "hue of tone {}: {}", tone, h);
+            assert!(c < 37., "chroma of tone {}: {}", tone, c);
+            assert!((t - tone).abs() < 0.5, "tone {}: {}", tone, t);
+        }
+        // the chroma is reachable at a middle tone
+        assert!((palette.tone(50.).hct().1 - 36.).abs() < 1.);
+    }
+
+    #[test]
+    fn test_core_palette() {
+        let core = CorePalette::new(&Color::from("#4285f4"));
+        assert!((core.primary.hue - 265.96).abs() < 0.01);
+        assert!((core.primary.chroma - 62.27).abs() < 0.01);
+        assert!((core.tertiary.hue - core.primary.hue - 60.).abs() < 1e-9);
+        assert_eq!(core.neutral.chroma, 4.);
+    }
+
+    #[test]
+    fn test_scheme() {
+        let seed = Color::from("#4285f4");
+        let light = Scheme::light(&seed);
+        let dark = Scheme::dark(&seed);
+        assert_eq!(light.background.hct().2.round(), 99.);
+        assert_eq!(dark.background.hct().2.round(), 10.);
+        assert_eq!(light.primary, dark.inverse_primary);
+        assert_eq!(light.on_primary.hex(), "#ffffff");
+        assert_eq!(dark.scrim.hex(), "#000000");
+    }
+}
@@ -1 +1,6 @@
+#[cfg(feature = "std")]
+pub mod distinct;
+pub mod material;
+#[cfg(feature = "std")]
 pub mod random;
+pub mod shades;
@@ -1,15 +1,200 @@
-use crate::Color;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
-static DIGITS: &str = "0123456789abcdef";
+use crate::{Color, GamutMapping};
+
+// Attempts at sampling RGB within the constraints before sampling OKLCh
+static MAX_TRIES: usize = 1000;
 
 /// Generate a random color.
 pub fn random() -> Color {
-    let mut code = "#".to_string();
-    for _ in 0..6 {
-        let index = rand::random::<usize>() % DIGITS.len();
-        code.push(DIGITS.chars().nth(index).unwrap());
+    random_with(&mut rand::thread_rng())
+}
+
+/// Generate a random color with the given random number generator.
+///
+/// ```
+/// use chroma_rust::random_with;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let a = random_with(&mut StdRng::seed_from_u64(42));
+/// let b = random_with(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(a, b);
+/// ```
+pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Color {
+    let (r, g, b) = rng.gen::<(u8, u8, u8)>();
+    Color::new(r, g, b, 1.0)
+}
+
+/// The space in which random colors are uniformly sampled.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum RandomMode {
+    /// Sample sRGB and reject colors outside of the constraints.
+    ///
+    /// After 1000 rejected colors, e.g. when the constraints are too narrow
+    /// for any sRGB color to meet them, a color is sampled in OKLCh instead.
+    #[default]
+    Rgb,
+    /// Sample OKLCh within the constraints, then bring the color into the sRGB gamut.
+    Oklch,
+}
+
+/// A generator of random colors constrained in OKLCh.
+///
+/// ```
+/// use chroma_rust::{RandomColor, RandomMode};
+///
+/// let generator = RandomColor::new()
+///     .mode(RandomMode::Oklch)
+///     .hue(200., 260.)
+///     .lightness(0.5, 0.7)
+///     .seed(7);
+/// let colors = generator.generate_many(5);
+/// assert_eq!(colors, generator.generate_many(5));
+/// for color in colors {
+///     let (l, _, h) = color.oklch();
+///     assert!((0.49..=0.71).contains(&l));
+///     assert!((195.0..=265.0).contains(&h));
+/// }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct RandomColor {
+    mode: RandomMode,
+    hue: (f64, f64),
+    lightness: (f64, f64),
+    chroma: (f64, f64),
+    alpha: (f64, f64),
+    seed: Option<u64>,
+}
+
+impl Default for RandomColor {
+    fn default() -> Self {
+        RandomColor {
+            mode: RandomMode::Rgb,
+            hue: (0., 360.),
+            lightness: (0., 1.),
+            chroma: (0., 0.4),
+            alpha: (1., 1.),
+            seed: None,
+        }
     }
-    Color::from(code.as_str())
+}
+
+impl RandomColor {
+    pub fn new() -> RandomColor {
+        RandomColor::default()
+    }
+
+    /// Set the space in which colors are uniformly sampled.
+    ///
+    /// In RGB, colors outside of the constraints are rejected, falling back to
+    /// OKLCh when none is found, see [`RandomMode::Rgb`]. In OKLCh, colors are
+    /// brought into the sRGB gamut, which may lower their chroma.
+    pub fn mode(mut self, mode: RandomMode) -> RandomColor {
+        self.mode = mode;
+        self
+    }
+
+    /// Constrain the OKLCh hue, in degrees.
+    ///
+    /// The range wraps around when `min > max`, e.g. `(330, 30)` for reds.
+    pub fn hue(mut self, min: f64, max: f64) -> RandomColor {
+        self.hue = (min.rem_euclid(360.), max.rem_euclid(360.));
+        if max - min >= 360. {
+            self.hue = (0., 360.);
+        }
+        self
+    }
+
+    /// Constrain the OKLCh lightness, in the range [0, 1].
+    pub fn lightness(mut self, min: f64, max: f64) -> RandomColor {
+        self.lightness = (min, max);
+        self
+    }
+
+    /// Constrain the OKLCh chroma, in the range [0, 0.4].
+    pub fn chroma(mut self, min: f64, max: f64) -> RandomColor {
+        self.chroma = (min, max);
+        self
+    }
+
+    /// Constrain the alpha, in the range [0, 1].
+    pub fn alpha(mut self, min: f64, max: f64) -> RandomColor {
+        self.alpha = (min, max);
+        self
+    }
+
+    /// Make the output reproducible.
+    pub fn seed(mut self, seed: u64) -> RandomColor {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Generate a color, from the seed if any.
+    pub fn generate(&self) -> Color {
+        match self.seed {
+            Some(seed) => self.generate_with(&mut StdRng::seed_from_u64(seed)),
+            None => self.generate_with(&mut rand::thread_rng()),
+        }
+    }
+
+    /// Generate `count` colors, from the seed if any.
+    pub fn generate_many(&self, count: usize) -> Vec<Color> {
+        match self.seed {
+            Some(seed) => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                (0..count).map(|_| self.generate_with(&mut rng)).collect()
+            }
+            None => {
+                let mut rng = rand::thread_rng();
+                (0..count).map(|_| self.generate_with(&mut rng)).collect()
+            }
+        }
+    }
+
+    /// Generate a color with the given random number generator, ignoring the seed.
+    pub fn generate_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        let mut color = match self.mode {
+            RandomMode::Rgb => (0..MAX_TRIES)
+                .map(|_| random_with(rng))
+                .find(|color| self.contains(color))
+                .unwrap_or_else(|| self.sample_oklch(rng)),
+            RandomMode::Oklch => self.sample_oklch(rng),
+        };
+        color.set_alpha(sample(rng, self.alpha));
+        color
+    }
+
+    fn sample_oklch<R: Rng + ?Sized>(&self, rng: &mut R) -> Color {
+        let (min, max) = self.hue;
+        let span = if min <= max {
+            max - min
+        } else {
+            max + 360. - min
+        };
+        let h = (min + rng.gen::<f64>() * span).rem_euclid(360.);
+        let l = sample(rng, self.lightness);
+        let c = sample(rng, self.chroma);
+        Color::from_oklch((l, c, h), GamutMapping::Css)
+    }
+
+    fn contains(&self, color: &Color) -> bool {
+        let (l, c, h) = color.oklch();
+        let (min, max) = self.hue;
+        let in_hue = if min <= max {
+            (min..=max).contains(&h)
+        } else {
+            h >= min || h <= max
+        };
+        in_hue
+            && (self.lightness.0..=self.lightness.1).contains(&l)
+            && (self.chroma.0..=self.chroma.1).contains(&c)
+    }
+}
+
+fn sample<R: Rng + ?Sized>(rng: &mut R, range: (f64, f64)) -> f64 {
+    let (min, max) = range;
+    min + rng.gen::<f64>() * (max - min)
 }
 
 #[cfg(test)]
@@ -30,4 +215,66 @@ mod tests {
             assert!(color.rgba.3.eq(&1.));
         }
     }
+
+    #[test]
+    fn test_random_with() {
+        let mut a = StdRng::seed_from_u64(1);
+        let mut b = StdRng::seed_from_u64(1);
+        for _ in 0..10 {
+            assert_eq!(random_with(&mut a), random_with(&mut b));
+        }
+    }
+
+    #[test]
+    fn test_random_color_seed() {
+        let generator = RandomColor::new().seed(3);
+        assert_eq!(generator.generate(), generator.generate());
+        let colors = generator.generate_many(10);
+        assert_eq!(colors, generator.generate_many(10));
+        assert_ne!(colors[0], colors[1]);
+    }
+
+    #[test]
+    fn test_random_color_rgb() {
+        let generator = RandomColor::new()
+            .hue(330., 30.)
+            .lightness(0.4, 0.8)
+            .chroma(0.1, 0.4)
+            .alpha(0.2, 0.6)
+            .seed(11);
+        for color in generator.generate_many(50) {
+            let (l, c, h) = color.oklch();
+            assert!(h >= 330. || h <= 30.);
+            assert!((0.4..=0.8).contains(&l));
+            assert!((0.1..=0.4).contains(&c));
+            assert!((0.2..=0.6).contains(&color.alpha()));
+        }
+    }
+
+    #[test]
+    fn test_random_color_rgb_fallback() {
+        // no sRGB color has exactly this lightness, so every RGB sample is rejected
+        let generator = RandomColor::new().lightness(0.5, 0.5).seed(2);
+        for color in generator.generate_many(5) {
+            let (l, _, _) = color.oklch();
+            assert!((l - 0.5).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_random_color_oklch() {
+        let generator = RandomColor::new()
+            .mode(RandomMode::Oklch)
+            .hue(100., 140.)
+            .lightness(0.6, 0.7)
+            .chroma(0.05, 0.1)
+            .seed(5);
+        for color in generator.generate_many(50) {
+            let (l, c, h) = color.oklch();
+            assert!((95. ..=145.).contains(&h));
+            assert!((0.59..=0.71).contains(&l));
+            assert!((0.04..=0.11).contains(&c));
+            assert_eq!(color.alpha(), 1.);
+        }
+    }
 }
@@ -0,0 +1,144 @@
+use alloc::collections::BTreeMap;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::{Color, GamutMapping};
+
+/// The steps of a Tailwind CSS shade ramp, from the lightest to the darkest.
+pub static STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// Options of the shade ramp, in OKLCh.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ShadeOptions {
+    /// OKLCh lightness of the lightest and the darkest steps
+    pub lightness: (f64, f64),
+    /// Exponent of the lightness curve, `1` is linear and greater values
+    /// spread the light steps
+    pub curve: f64,
+    /// Fraction of the chroma lost at the step the farthest from the anchor,
+    /// in the range [0, 1]
+    pub chroma_falloff: f64,
+}
+
+impl Default for ShadeOptions {
+    fn default() -> Self {
+        ShadeOptions {
+            lightness: (0.97, 0.28),
+            curve: 1.,
+            chroma_falloff: 0.4,
+        }
+    }
+}
+
+/// Generate an 11 step shade ramp (50, 100, ..., 900, 950) of a color.
+///
+/// See [`shades_with`].
+pub fn shades(color: &Color) -> BTreeMap<u16, Color> {
+    shades_with(color, &ShadeOptions::default())
+}
+
+/// Generate an 11 step shade ramp (50, 100, ..., 900, 950) of a color.
+///
+/// The color is kept at the step of the nearest lightness, the lightness of
+/// the other steps follows the curve towards the ends, the hue is kept and
+/// the chroma decreases away from the color.
+///
+/// ```
+/// use chroma_rust::{shades_with, Color, ShadeOptions};
+///
+/// let color = Color::from("#3b82f6");
+/// let shades = shades_with(&color, &ShadeOptions::default());
+/// assert_eq!(shades.len(), 11);
+/// assert_eq!(shades[&500], color);
+/// assert!(shades[&50].oklch().0 > shades[&950].oklch().0);
+/// ```
+pub fn shades_with(color: &Color, options: &ShadeOptions) -> BTreeMap<u16, Color> {
+    let (l, c, h) = color.oklch();
+    let (lightest, darkest) = options.lightness;
+    let last = STEPS.len() - 1;
+
+    let curve = |t: f64| t.powf(1. / options.curve);
+    let target = |i: usize| lightest + (darkest - lightest) * curve(i as f64 / last as f64);
+    let anchor = (0..=last)
+        .min_by(|&i, &j| (target(i) - l).abs().total_cmp(&(target(j) - l).abs()))
+        .unwrap();
+
+    STEPS
+        .iter()
+        .enumerate()
+        .map(|(i, &step)| {
+            if i == anchor {
+                return (step, *color);
+            }
+            // the curve is split at the anchor, so that it meets the color
+            let lightness = if i < anchor {
+                let t = curve(i as f64 / last as f64) / curve(anchor as f64 / last as f64);
+                lightest + (l - lightest) * t
+            } else {
+                let from = curve(anchor as f64 / last as f64);
+                let t = (curve(i as f64 / last as f64) - from) / (1. - from);
+                l + (darkest - l) * t
+            };
+            let distance = (i as f64 - anchor as f64).abs() / last as f64;
+            let chroma = c * (1. - options.chroma_falloff * distance);
+            let mut shade = Color::from_oklch((lightness, chroma, h), GamutMapping::Css);
+            shade.set_alpha(color.alpha());
+            (step, shade)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_shades() {
+        let color = Color::from("#3b82f6");
+        let shades = shades(&color);
+        assert_eq!(shades.keys().copied().collect::<Vec<_>>(), STEPS.to_vec());
+        assert_eq!(shades[&500], color);
+
+        let lightness: Vec<f64> = shades.values().map(|c| c.oklch().0).collect();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+        assert!((lightness[0] - 0.97).abs() < 0.01);
+        assert!((lightness[10] - 0.28).abs() < 0.01);
+        for (_, c, h) in shades.values().map(|c| c.oklch()) {
+            if c > 0.05 {
+                assert!((h - color.oklch().2).abs() < 10.);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shades_anchor() {
+        let light = Color::from("#dbeafe");
+        assert_eq!(shades(&light)[&100], light);
+
+        let dark = Color::from("#1e3a8a");
+        assert_eq!(shades(&dark)[&900], dark);
+        let shades = shades(&dark);
+        let lightness: Vec<f64> = shades.values().map(|c| c.oklch().0).collect();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn test_shades_options() {
+        let color = Color::from("#3b82f6");
+        let options = ShadeOptions {
+            chroma_falloff: 1.,
+            ..Default::default()
+        };
+        let flat = shades(&color);
+        let falling = shades_with(&color, &options);
+        assert!(falling[&900].oklch().1 < flat[&900].oklch().1);
+
+        let options = ShadeOptions {
+            curve: 2.,
+            ..Default::default()
+        };
+        let curved = shades_with(&color, &options);
+        assert!(curved[&100].oklch().0 < flat[&100].oklch().0);
+    }
+}
@@ -1,16 +1,56 @@
-#[macro_use]
-extern crate lazy_static;
+//! # Features
+//!
+//! - `std` (default): random colors, distinct colors and palette files.
+//!   Without it the crate is `no_std`, only needing `alloc`.
+//! - `libm`: the float math for `no_std`, required without `std`.
+//! - `serde`: serialization of colors, see the `serde` module. It only needs `alloc`.
+//! - `json`: reading and writing JSON palettes, with `std` and `serde`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// tests link `std` even without the feature, e.g. for `HashMap`
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature is required");
 
 mod chroma;
 mod color;
 mod data;
 mod generator;
 mod ops;
+#[cfg(feature = "std")]
+pub mod palette;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod utils;
 
 pub use chroma::Chroma;
-pub use color::Color;
-use data::w3cx11::W3CX11_HASHMAP as W3CX11;
-pub use generator::random::random;
-pub use utils::distance::distance;
+pub use color::{
+    Channels, Color, CssFormat, CssNotation, Lab, LinearRgb, NameDictionary, SortKey, TokenColor,
+    TokenColorSpace,
+};
+use data::w3cx11::W3CX11;
+#[cfg(feature = "std")]
+pub use generator::distinct::DistinctColors;
+pub use generator::material::{CorePalette, Scheme, TonalPalette};
+#[cfg(feature = "std")]
+pub use generator::random::{random, random_with, RandomColor, RandomMode};
+pub use generator::shades::{shades, shades_with, ShadeOptions};
+pub use ops::lerp::Lerp;
+pub use utils::conversion::adaptation::Adaptation;
+pub use utils::conversion::cam16::{Cam16, Surround, ViewingConditions};
+pub use utils::conversion::gamut::GamutMapping;
+pub use utils::conversion::hex::{HexAlpha, HexOptions};
+pub use utils::conversion::rgb_space::{RgbSpace, TransferFunction};
+pub use utils::conversion::white_point::WhitePoint;
+pub use utils::conversion::xyz::Xyz;
+pub use utils::conversion::ycbcr::{YCbCrRange, YCbCrStandard};
+pub use utils::cvd::{
+    check_palette, check_palette_with, CvdKind, CvdModel, PaletteReport, VisionReport,
+};
+pub use utils::distance::{delta_e, delta_e_with, distance, DeltaE};
+pub use utils::parser::ParseColorError;
 pub use utils::valid::valid;
@@ -0,0 +1,74 @@
+use crate::{utils::cvd, Color, CvdKind, CvdModel};
+
+impl Color {
+    /// Simulate how the color is seen with a color vision deficiency, with the
+    /// model of Machado et al.
+    ///
+    /// The severity goes from normal vision at `0` to the full deficiency at `1`.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, CvdKind};
+    ///
+    /// let red = Color::from("red").simulate_cvd(CvdKind::Deuteranopia, 1.);
+    /// let green = Color::from("green").simulate_cvd(CvdKind::Deuteranopia, 1.);
+    /// assert!(chroma_rust::delta_e(&red, &green) < 25.);
+    /// ```
+    pub fn simulate_cvd(&self, kind: CvdKind, severity: f64) -> Color {
+        self.simulate_cvd_with(kind, severity, CvdModel::Machado)
+    }
+
+    /// Simulate how the color is seen with a color vision deficiency, with the given model.
+    pub fn simulate_cvd_with(&self, kind: CvdKind, severity: f64, model: CvdModel) -> Color {
+        let (r, g, b) = cvd::simulate(self.rgb(), kind, severity, model);
+        Color::new(r, g, b, self.alpha())
+    }
+
+    /// Correct the color for a color vision deficiency ("daltonize"), shifting
+    /// the information that is lost into the channels that are still seen.
+    ///
+    /// Achromatopsia can't be corrected, the color is returned unchanged.
+    ///
+    /// ```
+    /// use chroma_rust::{delta_e, Color, CvdKind};
+    ///
+    /// let kind = CvdKind::Deuteranopia;
+    /// let (red, green) = (Color::from("#d62728"), Color::from("#2ca02c"));
+    /// let before = delta_e(&red.simulate_cvd(kind, 1.), &green.simulate_cvd(kind, 1.));
+    /// let (red, green) = (red.daltonize(kind, 1.), green.daltonize(kind, 1.));
+    /// let after = delta_e(&red.simulate_cvd(kind, 1.), &green.simulate_cvd(kind, 1.));
+    /// assert!(after > before);
+    /// ```
+    pub fn daltonize(&self, kind: CvdKind, severity: f64) -> Color {
+        let (r, g, b) = cvd::daltonize(self.rgb(), kind, severity);
+        Color::new(r, g, b, self.alpha())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_cvd() {
+        let color = Color::from("rgba(255, 0, 0, 0.5)");
+        assert_eq!(color.simulate_cvd(CvdKind::Protanopia, 0.), color);
+        let gray = color.simulate_cvd(CvdKind::Achromatopsia, 1.);
+        assert_eq!(gray.rgba(), (127, 127, 127, 0.5));
+
+        let partial = color.simulate_cvd(CvdKind::Protanopia, 0.5);
+        let full = color.simulate_cvd(CvdKind::Protanopia, 1.);
+        assert!(crate::delta_e(&color, &partial) < crate::delta_e(&color, &full));
+
+        let brettel = color.simulate_cvd_with(CvdKind::Tritanopia, 1., CvdModel::Brettel);
+        assert_ne!(brettel, color);
+    }
+
+    #[test]
+    fn test_daltonize() {
+        let color = Color::from("rgba(214, 39, 40, 0.5)");
+        let corrected = color.daltonize(CvdKind::Protanopia, 1.);
+        assert_ne!(corrected, color);
+        assert_eq!(corrected.alpha(), 0.5);
+        assert_eq!(color.daltonize(CvdKind::Achromatopsia, 1.), color);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{utils::conversion, Color};
+use crate::{Color, GamutMapping};
 
 // Corresponds roughly to RGB brighter/darker
 static KN: f64 = 18.;
@@ -6,7 +6,8 @@ static KN: f64 = 18.;
 impl Color {
     /// Darken a color by a given amount.
     ///
-    /// Default amount is 1.0.
+    /// Default amount is 1.0. Channels outside of the sRGB gamut are clipped,
+    /// like in chroma.js, see [`Color::darken_with`] to map them instead.
     ///
     /// Example:
     /// ```
@@ -14,34 +15,51 @@ impl Color {
     /// Color::from("hotpink").darken(Some(2.)); // #930058
     /// ```
     pub fn darken(&self, amount: Option<f64>) -> Color {
-        let amount = amount.unwrap_or(1.);
+        self.darken_with(amount.unwrap_or(1.), GamutMapping::Clip)
+    }
+
+    /// Darken a color by a given amount, bringing it into the sRGB gamut with the given method.
+    ///
+    /// ```
+    /// use chroma_rust::{Color, GamutMapping};
+    ///
+    /// let color = Color::from("hotpink");
+    /// assert_eq!(color.darken_with(2., GamutMapping::Css).hex(), "#910057");
+    /// ```
+    pub fn darken_with(&self, amount: f64, method: GamutMapping) -> Color {
         let lab = self.mode("lab");
         let alpha = self.alpha();
         let (l, a, b) = (lab[0] - KN * amount, lab[1], lab[2]);
-        let (r, g, b, _) = conversion::lab::lab2rgb((l, a, b));
-        Color::new(r, g, b, alpha)
+        let mut color = Color::from_lab((l, a, b), method);
+        color.set_alpha(alpha);
+        color
     }
     /// alias for darken
     pub fn darker(&self, amount: Option<f64>) -> Color {
         let amount = amount.unwrap_or(1.);
-        Color::darken(&self, Some(amount))
+        Color::darken(self, Some(amount))
     }
 
     /// Brighten a color by a given amount.
     pub fn brighten(&self, amount: Option<f64>) -> Color {
         let amount = amount.unwrap_or(1.);
-        Color::darken(&self, Some(amount * -1.))
+        Color::darken(self, Some(-amount))
+    }
+
+    /// Brighten a color by a given amount, bringing it into the sRGB gamut with the given method.
+    pub fn brighten_with(&self, amount: f64, method: GamutMapping) -> Color {
+        self.darken_with(-amount, method)
     }
     /// alias for brighten
     pub fn brighter(&self, amount: Option<f64>) -> Color {
         let amount = amount.unwrap_or(1.);
-        Color::brighten(&self, Some(amount))
+        Color::brighten(self, Some(amount))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Color;
+    use crate::{Color, GamutMapping};
 
     #[test]
     fn test_darken_color() {
@@ -54,6 +72,20 @@ mod tests {
         assert_eq!(darkened.name(), "#930058");
     }
 
+    #[test]
+    fn test_darken_with() {
+        let color = Color::from("hotpink");
+        assert_eq!(
+            color.darken_with(2., GamutMapping::Clip),
+            color.darken(Some(2.))
+        );
+        assert_eq!(color.darken_with(2., GamutMapping::Css).hex(), "#910057");
+        assert_eq!(
+            color.brighten_with(1., GamutMapping::Clip),
+            color.brighten(None)
+        );
+    }
+
     #[test]
     fn test_brighten_color() {
         let color = Color::from("#7760BF");
@@ -0,0 +1,160 @@
+use alloc::vec::Vec;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::{Color, Lab, LinearRgb};
+
+/// Linear interpolation, `t` going from `self` at 0 to `other` at 1.
+pub trait Lerp {
+    fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &f64, t: f64) -> f64 {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for LinearRgb {
+    fn lerp(&self, other: &LinearRgb, t: f64) -> LinearRgb {
+        *self + (*other - *self) * t
+    }
+}
+
+impl Lerp for Lab {
+    fn lerp(&self, other: &Lab, t: f64) -> Lab {
+        *self + (*other - *self) * t
+    }
+}
+
+impl Color {
+    /// Interpolate between two colors in the given mode (see [`Color::mode`]),
+    /// `t` going from `self` at 0 to `other` at 1. The alpha is interpolated too.
+    ///
+    /// Hues are interpolated along the shorter arc. Like in chroma.js, a gray has
+    /// no hue, so the hue of the other color is kept, as well as its saturation in
+    /// `hsl` when the gray is black or white.
+    ///
+    /// Like [`Color::vec_mode2color`], channels outside of the sRGB gamut are clipped.
+    ///
+    /// ```
+    /// use chroma_rust::Color;
+    ///
+    /// let red = Color::from("red");
+    /// let blue = Color::from("blue");
+    /// assert_eq!(red.lerp(&blue, 0.5, "rgb").hex(), "#800080");
+    /// assert_eq!(red.lerp(&blue, 0.5, "lab").hex(), "#ca0088");
+    /// assert_eq!(red.lerp(&blue, 0.5, "hsl").hex(), "#ff00ff");
+    ///
+    /// let gradient: Vec<String> = (0..=4)
+    ///     .map(|i| red.lerp(&blue, i as f64 / 4., "oklab").hex())
+    ///     .collect();
+    /// assert_eq!(gradient.len(), 5);
+    /// ```
+    pub fn lerp(&self, other: &Color, t: f64, mode: &str) -> Color {
+        let mut v1 = self.mode(mode);
+        let mut v2 = other.mode(mode);
+        if let Some(hue) = hue_index(mode) {
+            match (self.is_gray(), other.is_gray()) {
+                (false, false) => {
+                    // the shorter arc, possibly going past 0 or 360
+                    let delta = (v2[hue] - v1[hue] + 180.).rem_euclid(360.) - 180.;
+                    v2[hue] = v1[hue] + delta;
+                }
+                (false, true) => keep_hue(&v1, &mut v2, other, hue, mode),
+                (true, false) => keep_hue(&v2, &mut v1, self, hue, mode),
+                (true, true) => {}
+            }
+        }
+        let mut v: Vec<f64> = v1.iter().zip(&v2).map(|(a, b)| a.lerp(b, t)).collect();
+        if let Some(hue) = hue_index(mode) {
+            v[hue] = v[hue].rem_euclid(360.);
+        }
+        let mut color = Color::vec_mode2color(v, mode);
+        if mode != "rgba" {
+            color.set_alpha(self.alpha().lerp(&other.alpha(), t));
+        }
+        color
+    }
+
+    fn is_gray(&self) -> bool {
+        let (r, g, b) = self.rgb();
+        r == g && g == b
+    }
+}
+
+/// The index of the hue channel of a mode, if it has one.
+fn hue_index(mode: &str) -> Option<usize> {
+    match mode {
+        "hsl" | "hsv" | "hwb" | "hsi" | "hcg" | "hsluv" | "hpluv" | "hct" => Some(0),
+        "lch" | "oklch" | "lchuv" | "cam16" => Some(2),
+        _ => None,
+    }
+}
+
+/// Give the channels of a gray the hue of the other color, and in `hsl` its
+/// saturation too when the gray is black or white, so that e.g. mixing red with
+/// white stays a red.
+fn keep_hue(colored: &[f64], gray: &mut [f64], gray_color: &Color, hue: usize, mode: &str) {
+    gray[hue] = colored[hue];
+    let (r, _, _) = gray_color.rgb();
+    if mode == "hsl" && (r == 0 || r == 255) {
+        gray[1] = colored[1];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp() {
+        let red = Color::from("red");
+        let blue = Color::from("blue");
+        assert_eq!(red.lerp(&blue, 0., "lab"), red);
+        assert_eq!(red.lerp(&blue, 1., "lab"), blue);
+        assert_eq!(red.lerp(&blue, 0.5, "hsl").hex(), "#ff00ff");
+        assert_eq!(red.lerp(&blue, 0.5, "hwb").hex(), "#ff00ff");
+
+        let mut transparent = blue;
+        transparent.set_alpha(0.);
+        assert_eq!(red.lerp(&transparent, 0.25, "oklab").alpha(), 0.75);
+        assert_eq!(red.lerp(&transparent, 0.25, "rgba").alpha(), 0.75);
+    }
+
+    #[test]
+    fn test_lerp_hue() {
+        // the shorter arc across 0
+        let a = Color::from("hsl(350 100% 50%)");
+        let b = Color::from("hsl(30 100% 50%)");
+        assert!((a.lerp(&b, 0.5, "hsl").hsl().0 - 10.).abs() < 1.);
+        assert!((b.lerp(&a, 0.5, "hsl").hsl().0 - 10.).abs() < 1.);
+        let (_, _, hue) = a.lerp(&b, 0.25, "oklch").oklch();
+        let (_, _, h1) = a.oklch();
+        let (_, _, h2) = b.oklch();
+        let expected = h1 + ((h2 - h1 + 180.).rem_euclid(360.) - 180.) / 4.;
+        assert!((hue - expected.rem_euclid(360.)).abs() < 1., "{}", hue);
+
+        // a gray takes the hue of the other color
+        let red = Color::from("red");
+        let white = Color::from("white");
+        assert_eq!(red.lerp(&white, 0.5, "hsl").hex(), "#ff8080");
+        assert_eq!(white.lerp(&red, 0.5, "hsl").hex(), "#ff8080");
+        let gray = Color::from("#808080");
+        assert_eq!(Color::from("blue").lerp(&gray, 0.5, "hsv").hsv().0, 240.);
+        assert_eq!(gray.lerp(&white, 0.5, "hsl").hex(), "#c0c0c0");
+    }
+
+    #[test]
+    fn test_lerp_vectors() {
+        assert_eq!(2_f64.lerp(&4., 0.25), 2.5);
+
+        let a = LinearRgb::new(0., 0., 0., 1.);
+        let b = LinearRgb::new(1., 0.5, 0., 0.);
+        assert_eq!(a.lerp(&b, 0.5), LinearRgb::new(0.5, 0.25, 0., 0.5));
+
+        let a = Lab::new(0., -10., 10., 1.);
+        let b = Lab::new(100., 10., -10., 1.);
+        assert_eq!(a.lerp(&b, 0.5), Lab::new(50., 0., 0., 1.));
+    }
+}
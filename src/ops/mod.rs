@@ -1 +1,3 @@
+pub mod cvd;
 pub mod darken;
+pub mod lerp;
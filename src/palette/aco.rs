@@ -0,0 +1,153 @@
+use super::{from_lab_d50, non_empty, write_utf16, PaletteError, Reader};
+use crate::utils::conversion;
+use crate::Color;
+
+const RGB: u16 = 0;
+const HSB: u16 = 1;
+const CMYK: u16 = 2;
+const LAB: u16 = 7;
+const GRAYSCALE: u16 = 8;
+
+/// Parse an Adobe Color Swatch (`.aco`) file.
+///
+/// RGB, HSB, CMYK, Lab and grayscale colors are read. The names come from the
+/// version 2 section, when present.
+pub fn parse_aco(data: &[u8]) -> Result<Vec<(Option<String>, Color)>, PaletteError> {
+    let mut reader = Reader::new(data);
+    let mut colors = parse_section(&mut reader)?;
+    if !reader.is_empty() {
+        colors = parse_section(&mut reader)?;
+    }
+    Ok(colors)
+}
+
+fn parse_section(reader: &mut Reader) -> Result<Vec<(Option<String>, Color)>, PaletteError> {
+    let version = reader.u16()?;
+    if version != 1 && version != 2 {
+        let msg = format!("unknown version {}", version);
+        return Err(PaletteError::InvalidFormat(msg));
+    }
+    let count = reader.u16()?;
+    (0..count)
+        .map(|_| {
+            let color = parse_color(reader)?;
+            let name = if version == 2 {
+                let len = reader.u32()? as usize;
+                reader.utf16(len)?
+            } else {
+                String::new()
+            };
+            Ok((non_empty(name), color))
+        })
+        .collect()
+}
+
+fn parse_color(reader: &mut Reader) -> Result<Color, PaletteError> {
+    let space = reader.u16()?;
+    let (w, x, y, z) = (reader.u16()?, reader.u16()?, reader.u16()?, reader.u16()?);
+    let unit = |v: u16| v as f64 / 65535.;
+    let (r, g, b) = match space {
+        RGB => {
+            let channel = |v: u16| (v as f64 / 257.).round() as u8;
+            (channel(w), channel(x), channel(y))
+        }
+        HSB => conversion::hsv::hsv2rgb((unit(w) * 360. % 360., unit(x), unit(y))),
+        // 0 is 100% of ink
+        CMYK => {
+            let cmyk = (1. - unit(w), 1. - unit(x), 1. - unit(y), 1. - unit(z));
+            conversion::cmyk::cmyk2rgb(cmyk)
+        }
+        LAB => {
+            let lab = (
+                w as f64 / 100.,
+                x as i16 as f64 / 100.,
+                y as i16 as f64 / 100.,
+            );
+            return Ok(from_lab_d50(lab));
+        }
+        // The amount of black, in 0..10000
+        GRAYSCALE => {
+            let v = (255. * (1. - w as f64 / 10000.)).round() as u8;
+            (v, v, v)
+        }
+        s => {
+            let msg = format!("unknown color space {}", s);
+            return Err(PaletteError::InvalidFormat(msg));
+        }
+    };
+    Ok(Color::new(r, g, b, 1.))
+}
+
+/// Write an Adobe Color Swatch (`.aco`) file of RGB colors, ignoring the alpha.
+///
+/// Both the version 1 section and the version 2 section with the names are written.
+pub fn write_aco(colors: &[(Option<String>, Color)]) -> Vec<u8> {
+    let mut out = vec![];
+    for version in [1_u16, 2] {
+        out.extend(version.to_be_bytes());
+        out.extend((colors.len() as u16).to_be_bytes());
+        for (name, color) in colors {
+            let (r, g, b) = color.rgb();
+            for v in [RGB, r as u16 * 257, g as u16 * 257, b as u16 * 257, 0] {
+                out.extend(v.to_be_bytes());
+            }
+            if version == 2 {
+                let name = name.as_deref().unwrap_or("");
+                out.extend((name.encode_utf16().count() as u32 + 1).to_be_bytes());
+                write_utf16(&mut out, name);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<(Option<String>, Color)> {
+        [
+            ("Red", "#ff0000"),
+            ("Steel", "#336699"),
+            ("Amber", "#ffcc00"),
+            ("", "#000000"),
+            ("Blanc", "#ffffff"),
+        ]
+        .iter()
+        .map(|(n, hex)| (non_empty(n.to_string()), Color::from(*hex)))
+        .collect()
+    }
+
+    #[test]
+    fn test_parse_aco() {
+        let data = include_bytes!("fixtures/palette.aco");
+        assert_eq!(parse_aco(data).unwrap(), fixture());
+    }
+
+    #[test]
+    fn test_write_aco() {
+        let data = include_bytes!("fixtures/palette.aco");
+        assert_eq!(write_aco(&fixture()), data);
+    }
+
+    #[test]
+    fn test_parse_aco_models() {
+        let data = include_bytes!("fixtures/models.aco");
+        let colors = parse_aco(data).unwrap();
+        let hex: Vec<String> = colors.iter().map(|(_, c)| c.hex()).collect();
+        assert_eq!(hex, ["#00ff00", "#00ffff", "#ffffff", "#808080"]);
+        assert!(colors.iter().all(|(name, _)| name.is_none()));
+    }
+
+    #[test]
+    fn test_parse_aco_invalid() {
+        assert!(matches!(
+            parse_aco(&[0, 3, 0, 0]),
+            Err(PaletteError::InvalidFormat(_))
+        ));
+        assert_eq!(
+            parse_aco(&[0, 1, 0, 1, 0]),
+            Err(PaletteError::UnexpectedEof)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use super::{from_lab_d50, non_empty, write_utf16, PaletteError, Reader};
+use crate::utils::conversion;
+use crate::Color;
+
+const SIGNATURE: &[u8] = b"ASEF";
+const GROUP_START: u16 = 0xc001;
+const GROUP_END: u16 = 0xc002;
+const COLOR_ENTRY: u16 = 0x0001;
+// Normal color, as opposed to global and spot colors
+const COLOR_TYPE: u16 = 2;
+
+/// Parse an Adobe Swatch Exchange (`.ase`) file.
+///
+/// RGB, CMYK, Lab and gray colors are read, groups are flattened.
+pub fn parse_ase(data: &[u8]) -> Result<Vec<(Option<String>, Color)>, PaletteError> {
+    let mut reader = Reader::new(data);
+    if reader.bytes(4)? != SIGNATURE {
+        return Err(PaletteError::InvalidFormat(
+            "missing ASEF signature".to_string(),
+        ));
+    }
+    let _version = (reader.u16()?, reader.u16()?);
+    let count = reader.u32()?;
+
+    let mut colors = vec![];
+    for _ in 0..count {
+        let kind = reader.u16()?;
+        let len = reader.u32()? as usize;
+        let mut block = Reader::new(reader.bytes(len)?);
+        match kind {
+            COLOR_ENTRY => colors.push(parse_color(&mut block)?),
+            GROUP_START | GROUP_END => {}
+            k => {
+                let msg = format!("unknown block type {:#06x}", k);
+                return Err(PaletteError::InvalidFormat(msg));
+            }
+        }
+    }
+    Ok(colors)
+}
+
+fn parse_color(block: &mut Reader) -> Result<(Option<String>, Color), PaletteError> {
+    let name_len = block.u16()? as usize;
+    let name = block.utf16(name_len)?;
+    let model = block.bytes(4)?;
+    let mut values = |n: usize| -> Result<Vec<f64>, PaletteError> {
+        (0..n).map(|_| Ok(block.f32()? as f64)).collect()
+    };
+    let color = match model {
+        b"RGB " => {
+            let v = values(3)?;
+            let channel = |v: f64| (v * 255.).round().clamp(0., 255.) as u8;
+            Color::new(channel(v[0]), channel(v[1]), channel(v[2]), 1.)
+        }
+        b"CMYK" => {
+            let v = values(4)?;
+            let (r, g, b) = conversion::cmyk::cmyk2rgb((v[0], v[1], v[2], v[3]));
+            Color::new(r, g, b, 1.)
+        }
+        b"LAB " => {
+            let v = values(3)?;
+            from_lab_d50((v[0] * 100., v[1], v[2]))
+        }
+        b"Gray" => {
+            let v = (values(1)?[0] * 255.).round().clamp(0., 255.) as u8;
+            Color::new(v, v, v, 1.)
+        }
+        _ => {
+            let msg = format!("unknown color model {:?}", String::from_utf8_lossy(model));
+            return Err(PaletteError::InvalidFormat(msg));
+        }
+    };
+    Ok((non_empty(name), color))
+}
+
+/// Write an Adobe Swatch Exchange (`.ase`) file of RGB colors, ignoring the alpha.
+pub fn write_ase(colors: &[(Option<String>, Color)]) -> Vec<u8> {
+    let mut out = SIGNATURE.to_vec();
+    out.extend(1_u16.to_be_bytes());
+    out.extend(0_u16.to_be_bytes());
+    out.extend((colors.len() as u32).to_be_bytes());
+
+    for (name, color) in colors {
+        let name = name.as_deref().unwrap_or("");
+        let mut block = vec![];
+        block.extend((name.encode_utf16().count() as u16 + 1).to_be_bytes());
+        write_utf16(&mut block, name);
+        block.extend(b"RGB ");
+        let (r, g, b) = color.rgb();
+        for v in [r, g, b] {
+            block.extend((v as f32 / 255.).to_be_bytes());
+        }
+        block.extend(COLOR_TYPE.to_be_bytes());
+
+        out.extend(COLOR_ENTRY.to_be_bytes());
+        out.extend((block.len() as u32).to_be_bytes());
+        out.extend(block);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<(Option<String>, Color)> {
+        [
+            ("Red", "#ff0000"),
+            ("Steel", "#336699"),
+            ("Amber", "#ffcc00"),
+            ("", "#000000"),
+            ("Blanc", "#ffffff"),
+        ]
+        .iter()
+        .map(|(n, hex)| (non_empty(n.to_string()), Color::from(*hex)))
+        .collect()
+    }
+
+    #[test]
+    fn test_parse_ase() {
+        let data = include_bytes!("fixtures/palette.ase");
+        assert_eq!(parse_ase(data).unwrap(), fixture());
+    }
+
+    #[test]
+    fn test_write_ase() {
+        let data = include_bytes!("fixtures/palette.ase");
+        assert_eq!(write_ase(&fixture()), data);
+    }
+
+    #[test]
+    fn test_parse_ase_models() {
+        let data = include_bytes!("fixtures/models.ase");
+        let colors = parse_ase(data).unwrap();
+        let hex: Vec<String> = colors.iter().map(|(_, c)| c.hex()).collect();
+        assert_eq!(hex, ["#00ffff", "#808080", "#ffffff", "#ff0000"]);
+        assert_eq!(colors[0].0.as_deref(), Some("Cyan"));
+    }
+
+    #[test]
+    fn test_parse_ase_invalid() {
+        assert!(matches!(
+            parse_ase(b"ACOF\0\x01\0\0\0\0\0\0"),
+            Err(PaletteError::InvalidFormat(_))
+        ));
+        let data = include_bytes!("fixtures/palette.ase");
+        assert_eq!(
+            parse_ase(&data[..data.len() - 1]),
+            Err(PaletteError::UnexpectedEof)
+        );
+    }
+}
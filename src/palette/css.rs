@@ -0,0 +1,99 @@
+use super::PaletteError;
+use crate::color::color::named_hex;
+use crate::Color;
+
+/// Parse the colors of CSS custom properties, e.g. `:root { --accent: #ff8000; }`.
+///
+/// Properties whose value isn't a color, e.g. `--gap: 4px` or `--z-index: 100`,
+/// are skipped. Colors are `#` hex strings, color functions or color names.
+pub fn parse_css_vars(css: &str) -> Result<Vec<(Option<String>, Color)>, PaletteError> {
+    let colors = strip_comments(css)
+        .split(['{', '}', ';'])
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let name = property.trim().strip_prefix("--")?;
+            let value = value.trim();
+            if !is_color_value(value) {
+                return None;
+            }
+            let color = value.parse().ok()?;
+            Some((Some(name.to_string()), color))
+        })
+        .collect();
+    Ok(colors)
+}
+
+/// Whether a value looks like a color, rather than e.g. a number or a keyword.
+fn is_color_value(value: &str) -> bool {
+    let is_function = value
+        .split_once('(')
+        .is_some_and(|(name, _)| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()));
+    value.starts_with('#') || is_function || named_hex(&value.to_lowercase()).is_some()
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::new();
+    let mut rest = css;
+    while let Some((before, after)) = rest.split_once("/*") {
+        out.push_str(before);
+        rest = after.split_once("*/").map_or("", |(_, after)| after);
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Write the colors as CSS custom properties of `:root`.
+///
+/// Colors without a name are written as `--color-1`, `--color-2`, etc. Opaque
+/// colors are written as hex, translucent ones as `rgb()` to keep their alpha exact.
+pub fn write_css_vars(colors: &[(Option<String>, Color)]) -> String {
+    let mut out = String::from(":root {\n");
+    for (i, (name, color)) in colors.iter().enumerate() {
+        let name = match name {
+            Some(name) => name.split_whitespace().collect::<Vec<_>>().join("-"),
+            None => format!("color-{}", i + 1),
+        };
+        let value = if color.alpha() < 1. {
+            color.to_string()
+        } else {
+            color.hex()
+        };
+        out += &format!("  --{}: {};\n", name, value);
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_css_vars() {
+        let colors = parse_css_vars(include_str!("fixtures/palette.css")).unwrap();
+        let names: Vec<&str> = colors.iter().map(|(n, _)| n.as_deref().unwrap()).collect();
+        assert_eq!(names, ["red", "steel", "amber", "shadow", "white"]);
+        assert_eq!(colors[1].1, Color::from("#336699"));
+        assert_eq!(colors[3].1, Color::new(0, 0, 0, 0.5));
+    }
+
+    #[test]
+    fn test_css_vars_round_trip() {
+        let colors = parse_css_vars(include_str!("fixtures/palette.css")).unwrap();
+        let css = write_css_vars(&colors);
+        assert!(css.starts_with(":root {\n  --red: #ff0000;\n"));
+        assert_eq!(parse_css_vars(&css).unwrap(), colors);
+    }
+
+    #[test]
+    fn test_write_css_vars_unnamed() {
+        let colors = [
+            (None, Color::from("red")),
+            (Some("Steel Blue".into()), Color::from("#369")),
+        ];
+        assert_eq!(
+            write_css_vars(&colors),
+            ":root {\n  --color-1: #ff0000;\n  --Steel-Blue: #336699;\n}\n"
+        );
+    }
+}
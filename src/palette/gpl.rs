@@ -0,0 +1,85 @@
+use super::{non_empty, PaletteError};
+use crate::Color;
+
+/// Parse a GIMP palette (`.gpl`) file.
+pub fn parse_gpl(text: &str) -> Result<Vec<(Option<String>, Color)>, PaletteError> {
+    let mut lines = text.lines();
+    if lines.next().map(str::trim) != Some("GIMP Palette") {
+        return Err(PaletteError::InvalidFormat(
+            "missing GIMP Palette header".to_string(),
+        ));
+    }
+
+    let mut colors = vec![];
+    for line in lines.map(str::trim) {
+        if line.is_empty()
+            || line.starts_with('#')
+            || line.starts_with("Name:")
+            || line.starts_with("Columns:")
+        {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let channel = |i: usize| -> Result<u8, PaletteError> {
+            fields
+                .get(i)
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| PaletteError::InvalidFormat(format!("invalid color `{}`", line)))
+        };
+        let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+        let rest = fields.get(3..).unwrap_or_default().join(" ");
+        colors.push((non_empty(rest), Color::new(r, g, b, 1.)));
+    }
+    Ok(colors)
+}
+
+/// Write a GIMP palette (`.gpl`) file, ignoring the alpha.
+pub fn write_gpl(colors: &[(Option<String>, Color)]) -> String {
+    let mut out = String::from("GIMP Palette\n#\n");
+    for (name, color) in colors {
+        let (r, g, b) = color.rgb();
+        out += &format!("{:3} {:3} {:3}", r, g, b);
+        if let Some(name) = name {
+            out += &format!("\t{}", name);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpl() {
+        let colors = parse_gpl(include_str!("fixtures/palette.gpl")).unwrap();
+        let expected = [
+            (Some("Red"), "#ff0000"),
+            (Some("Steel Blue"), "#336699"),
+            (Some("Amber"), "#ffcc00"),
+            (None, "#000000"),
+            (Some("Blanc"), "#ffffff"),
+        ];
+        assert_eq!(colors.len(), expected.len());
+        for ((name, color), (expected_name, hex)) in colors.iter().zip(expected) {
+            assert_eq!(name.as_deref(), expected_name);
+            assert_eq!(color.hex(), hex);
+        }
+    }
+
+    #[test]
+    fn test_gpl_round_trip() {
+        let colors = parse_gpl(include_str!("fixtures/palette.gpl")).unwrap();
+        let text = write_gpl(&colors);
+        assert!(text.starts_with("GIMP Palette\n#\n255   0   0\tRed\n"));
+        assert_eq!(parse_gpl(&text).unwrap(), colors);
+    }
+
+    #[test]
+    fn test_parse_gpl_invalid() {
+        assert!(parse_gpl("Name: palette\n255 0 0").is_err());
+        assert!(parse_gpl("GIMP Palette\n255 0\n").is_err());
+        assert!(parse_gpl("GIMP Palette\n256 0 0\n").is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use serde_json::Value;
+
+use super::PaletteError;
+use crate::{Color, TokenColor};
+
+/// Parse a JSON palette, behind the `json` feature.
+///
+/// Either an array of color strings or `{ "name": ..., "color": ... }` objects,
+/// as written by [`write_json`], or an object mapping names to color strings.
+///
+/// The object may also hold [design tokens](https://tr.designtokens.org/format/),
+/// e.g. `{ "brand": { "$type": "color", "$value": "#ff0000" } }`, in nested
+/// groups. A token is named by its path, e.g. `brand.primary`, and its `$value`
+/// is a color string or a [`TokenColor`]. Tokens of another `$type` are skipped,
+/// aliases aren't resolved.
+///
+/// Colors are read in the order of the file.
+pub fn parse_json(json: &str) -> Result<Vec<(Option<String>, Color)>, PaletteError> {
+    let node: Node = serde_json::from_str(json).map_err(|err| invalid(&err.to_string()))?;
+    match node {
+        Node::Array(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Node::Other(Value::String(color)) => Ok((None, color.parse()?)),
+                Node::Object(swatch) => {
+                    let name = field(&swatch, "name").and_then(Node::as_str);
+                    let color = field(&swatch, "color")
+                        .and_then(Node::as_str)
+                        .ok_or_else(|| invalid("missing color"))?;
+                    Ok((name.map(String::from), color.parse()?))
+                }
+                _ => Err(invalid("expected a color string or object")),
+            })
+            .collect(),
+        Node::Object(entries) => {
+            let mut colors = Vec::new();
+            parse_group(entries, None, None, &mut colors)?;
+            Ok(colors)
+        }
+        _ => Err(invalid("expected an array or an object")),
+    }
+}
+
+/// Read the colors of a group of design tokens, or of a flat object of color strings.
+fn parse_group(
+    entries: Vec<(String, Node)>,
+    path: Option<&str>,
+    group_type: Option<&str>,
+    colors: &mut Vec<(Option<String>, Color)>,
+) -> Result<(), PaletteError> {
+    let group_type = field(&entries, "$type")
+        .and_then(Node::as_str)
+        .or(group_type)
+        .map(String::from);
+    for (key, node) in entries {
+        if key.starts_with('$') {
+            continue;
+        }
+        let name = match path {
+            Some(path) => format!("{}.{}", path, key),
+            None => key,
+        };
+        match node {
+            Node::Other(Value::String(color)) => colors.push((Some(name), color.parse()?)),
+            Node::Object(token) if field(&token, "$value").is_some() => {
+                let token_type = field(&token, "$type")
+                    .and_then(Node::as_str)
+                    .or(group_type.as_deref());
+                if token_type.is_some_and(|t| t != "color") {
+                    continue;
+                }
+                let value = token
+                    .into_iter()
+                    .find(|(key, _)| key == "$value")
+                    .unwrap()
+                    .1;
+                let color = match value {
+                    Node::Other(Value::String(color)) => color.parse()?,
+                    value @ Node::Object(_) => {
+                        let token: TokenColor = serde_json::from_value(value.into_value())
+                            .map_err(|err| invalid(&err.to_string()))?;
+                        Color::from_design_token(&token)
+                    }
+                    _ => return Err(invalid("expected a color string or object as $value")),
+                };
+                colors.push((Some(name), color));
+            }
+            Node::Object(group) => parse_group(group, Some(&name), group_type.as_deref(), colors)?,
+            _ => return Err(invalid("expected a color string, a token or a group")),
+        }
+    }
+    Ok(())
+}
+
+fn invalid(msg: &str) -> PaletteError {
+    PaletteError::InvalidFormat(msg.to_string())
+}
+
+fn field<'a>(entries: &'a [(String, Node)], key: &str) -> Option<&'a Node> {
+    entries.iter().find(|(k, _)| k == key).map(|(_, node)| node)
+}
+
+/// A JSON value keeping the order of the object keys, whatever the features
+/// of serde_json.
+enum Node {
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+    Other(Value),
+}
+
+impl Node {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Node::Other(value) => value.as_str(),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Node::Array(items) => Value::Array(items.into_iter().map(Node::into_value).collect()),
+            Node::Object(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, node)| (key, node.into_value()))
+                    .collect(),
+            ),
+            Node::Other(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Node, E> {
+        Ok(Node::Other(Value::from(v)))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Node, E> {
+        Ok(Node::Other(Value::from(v)))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Node, E> {
+        Ok(Node::Other(Value::from(v)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Node, E> {
+        Ok(Node::Other(Value::from(v)))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Node, E> {
+        Ok(Node::Other(Value::from(v)))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Node, E> {
+        Ok(Node::Other(Value::Null))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Node::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Node::Object(entries))
+    }
+}
+
+/// Write a JSON array of `{ "name": ..., "color": ... }` objects, behind the `json` feature.
+///
+/// The name is omitted when there's none.
+pub fn write_json(colors: &[(Option<String>, Color)]) -> String {
+    let swatches: Vec<Swatch> = colors
+        .iter()
+        .map(|(name, color)| Swatch(name.as_deref(), color.hex()))
+        .collect();
+    serde_json::to_string_pretty(&swatches).unwrap()
+}
+
+/// A named color, serialized with the name first.
+struct Swatch<'a>(Option<&'a str>, String);
+
+impl Serialize for Swatch<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Swatch", 2)?;
+        match self.0 {
+            Some(name) => state.serialize_field("name", name)?,
+            None => state.skip_field("name")?,
+        }
+        state.serialize_field("color", &self.1)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json() {
+        let colors = parse_json(include_str!("fixtures/palette.json")).unwrap();
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0], (Some("Red".into()), Color::from("#ff0000")));
+        assert_eq!(colors[3], (None, Color::from("#000000")));
+
+        let colors = parse_json(r##"["#ff0000", "rgb(0 0 255)"]"##).unwrap();
+        assert_eq!(colors[1], (None, Color::from("blue")));
+
+        let colors = parse_json(r##"{ "accent": "#ff8000" }"##).unwrap();
+        assert_eq!(colors[0], (Some("accent".into()), Color::from("#ff8000")));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let json = include_str!("fixtures/palette.json");
+        let colors = parse_json(json).unwrap();
+        assert_eq!(write_json(&colors), json.trim_end());
+    }
+
+    #[test]
+    fn test_parse_json_tokens() {
+        let colors = parse_json(include_str!("fixtures/tokens.json")).unwrap();
+        let names: Vec<&str> = colors.iter().map(|(n, _)| n.as_deref().unwrap()).collect();
+        assert_eq!(
+            names,
+            [
+                "zinc",
+                "brand.primary",
+                "brand.accent.light",
+                "brand.accent.dark",
+                "alert"
+            ]
+        );
+        assert_eq!(colors[0].1, Color::from("#71717a"));
+        assert_eq!(colors[1].1, Color::from("#ff0000"));
+        assert_eq!(colors[2].1, Color::from("#ff8000"));
+        assert_eq!(colors[4].1, Color::new(255, 0, 0, 0.5));
+
+        // the keys keep their order
+        let colors = parse_json(r##"{ "zeta": "#000", "alpha": "#fff" }"##).unwrap();
+        assert_eq!(colors[0].0.as_deref(), Some("zeta"));
+    }
+
+    #[test]
+    fn test_parse_json_invalid() {
+        assert!(matches!(
+            parse_json("[1, 2]"),
+            Err(PaletteError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse_json(r#"["bread"]"#),
+            Err(PaletteError::InvalidColor(_))
+        ));
+        assert!(matches!(
+            parse_json(r#"{ "a": { "$type": "color", "$value": 1 } }"#),
+            Err(PaletteError::InvalidFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,127 @@
+//! Reading and writing palette files.
+//!
+//! A palette is a list of colors with optional names. Binary formats are read
+//! from and written to bytes, text formats to strings.
+//!
+//! ```
+//! use chroma_rust::{palette, Color};
+//!
+//! let gpl = "GIMP Palette\n#\n255 128   0\tOrange\n 51 102 153\n";
+//! let colors = palette::parse_gpl(gpl).unwrap();
+//! assert_eq!(colors[0], (Some("Orange".to_string()), Color::from("#ff8000")));
+//! assert_eq!(colors[1], (None, Color::from("#336699")));
+//!
+//! let ase = palette::write_ase(&colors);
+//! assert_eq!(palette::parse_ase(&ase).unwrap(), colors);
+//! ```
+
+mod aco;
+mod ase;
+mod css;
+mod gpl;
+#[cfg(feature = "json")]
+mod json;
+
+use std::{error, fmt};
+
+pub use aco::{parse_aco, write_aco};
+pub use ase::{parse_ase, write_ase};
+pub use css::{parse_css_vars, write_css_vars};
+pub use gpl::{parse_gpl, write_gpl};
+#[cfg(feature = "json")]
+pub use json::{parse_json, write_json};
+
+use crate::{Adaptation, Color, ParseColorError, WhitePoint, Xyz};
+
+/// The error returned when a palette file can't be read.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PaletteError {
+    /// The data isn't in the expected format
+    InvalidFormat(String),
+    /// The data ends in the middle of a color
+    UnexpectedEof,
+    /// A color couldn't be parsed
+    InvalidColor(ParseColorError),
+}
+
+impl fmt::Display for PaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaletteError::InvalidFormat(s) => write!(f, "Invalid palette: {}", s),
+            PaletteError::UnexpectedEof => write!(f, "Unexpected end of the palette"),
+            PaletteError::InvalidColor(err) => write!(f, "Invalid palette color: {}", err),
+        }
+    }
+}
+
+impl error::Error for PaletteError {}
+
+impl From<ParseColorError> for PaletteError {
+    fn from(err: ParseColorError) -> Self {
+        PaletteError::InvalidColor(err)
+    }
+}
+
+/// A cursor over big-endian binary data.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Reader<'a> {
+        Reader { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], PaletteError> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or(PaletteError::UnexpectedEof)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, PaletteError> {
+        Ok(u16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, PaletteError> {
+        Ok(u32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32, PaletteError> {
+        Ok(f32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    /// A UTF-16 string of `len` code units, ending with a null.
+    fn utf16(&mut self, len: usize) -> Result<String, PaletteError> {
+        let units: Vec<u16> = (0..len).map(|_| self.u16()).collect::<Result<_, _>>()?;
+        let units = units.strip_suffix(&[0]).unwrap_or(&units);
+        String::from_utf16(units)
+            .map_err(|_| PaletteError::InvalidFormat("invalid UTF-16 name".to_string()))
+    }
+}
+
+/// Write a UTF-16 string ending with a null.
+fn write_utf16(out: &mut Vec<u8>, s: &str) {
+    for unit in s.encode_utf16().chain([0]) {
+        out.extend(unit.to_be_bytes());
+    }
+}
+
+/// Swatch files store CIE Lab relative to the D50 white.
+fn from_lab_d50(lab: (f64, f64, f64)) -> Color {
+    let xyz = crate::utils::conversion::lab::lab2xyz(lab, WhitePoint::D50);
+    let xyz = Adaptation::Bradford.adapt(xyz, WhitePoint::D50, WhitePoint::D65);
+    Color::from(Xyz::from(xyz))
+}
+
+/// An empty name is no name.
+fn non_empty(name: String) -> Option<String> {
+    Some(name).filter(|name| !name.is_empty())
+}
@@ -0,0 +1,272 @@
+//! Serialization of [`Color`] with serde, behind the `serde` feature.
+//!
+//! A color is serialized as a hex string by default. The submodules select
+//! another representation with `#[serde(with = "...")]`. Strings are parsed
+//! like [`Color::from_str`], so an invalid color is an error instead of a panic.
+//!
+//! Each representation is read back by its own module, the default one reading
+//! any color string. Only `css` accepts any of them, as it needs a
+//! self-describing format like JSON.
+//!
+//! ```
+//! use chroma_rust::Color;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Theme {
+//!     accent: Color,
+//!     #[serde(with = "chroma_rust::serde::css")]
+//!     background: Color,
+//!     #[serde(with = "chroma_rust::serde::rgba")]
+//!     border: Color,
+//!     #[serde(with = "chroma_rust::serde::array")]
+//!     shadow: Color,
+//! }
+//!
+//! let json = r##"{
+//!     "accent": "#ff8000",
+//!     "background": "rgb(255 255 255)",
+//!     "border": { "r": 0, "g": 0, "b": 0, "a": 0.5 },
+//!     "shadow": [0, 0, 0, 0.2]
+//! }"##;
+//! let theme: Theme = serde_json::from_str(json).unwrap();
+//! assert_eq!(theme.border, Color::new(0, 0, 0, 0.5));
+//!
+//! let err = serde_json::from_str::<Theme>(&json.replace("#ff8000", "#ff800"));
+//! assert!(err.is_err());
+//! ```
+//!
+//! [`Color::from_str`]: core::str::FromStr::from_str
+
+use alloc::string::String;
+use core::fmt;
+
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Color;
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        hex::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+/// A hex string, e.g. `"#ff8000"` or `"#ff800080"`.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        Color::deserialize(deserializer)
+    }
+}
+
+/// A CSS string, e.g. `"rgb(255 128 0 / 0.5)"`, see [`Color::to_string`].
+///
+/// Any string, struct or array representation is accepted on deserialization.
+pub mod css {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(color)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+/// A struct, e.g. `{ "r": 255, "g": 128, "b": 0, "a": 0.5 }`.
+pub mod rgba {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let (r, g, b, a) = color.rgba();
+        let mut state = serializer.serialize_struct("Color", 4)?;
+        state.serialize_field("r", &r)?;
+        state.serialize_field("g", &g)?;
+        state.serialize_field("b", &b)?;
+        state.serialize_field("a", &a)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_struct("Color", FIELDS, ColorVisitor)
+    }
+}
+
+/// An array, e.g. `[255, 128, 0, 0.5]`.
+pub mod array {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let (r, g, b, a) = color.rgba();
+        let mut state = serializer.serialize_tuple(4)?;
+        state.serialize_element(&r)?;
+        state.serialize_element(&g)?;
+        state.serialize_element(&b)?;
+        state.serialize_element(&a)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        deserializer.deserialize_tuple(4, ColorVisitor)
+    }
+}
+
+/// A design token color in sRGB, e.g.
+/// `{ "colorSpace": "srgb", "components": [1, 0.5, 0], "alpha": 1, "hex": "#ff8000" }`,
+/// see [`TokenColor`](crate::TokenColor).
+pub mod design_token {
+    use super::*;
+    use crate::{RgbSpace, TokenColor, TokenColorSpace};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        color
+            .design_token(TokenColorSpace::Rgb(RgbSpace::SRGB))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        Ok(Color::from_design_token(&TokenColor::deserialize(
+            deserializer,
+        )?))
+    }
+}
+
+static FIELDS: &[&str] = &["r", "g", "b", "a"];
+
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a color string, an {r, g, b, a} struct or an [r, g, b, a] array")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Color, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+        let channel = |v: Option<u8>, i| v.ok_or_else(|| de::Error::invalid_length(i, &self));
+        let r = channel(seq.next_element()?, 0)?;
+        let g = channel(seq.next_element()?, 1)?;
+        let b = channel(seq.next_element()?, 2)?;
+        let a = seq.next_element()?.unwrap_or(1.);
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(5, &self));
+        }
+        Ok(Color::new(r, g, b, a))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Color, A::Error> {
+        let (mut r, mut g, mut b, mut a) = (None, None, None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "r" => r = Some(map.next_value()?),
+                "g" => g = Some(map.next_value()?),
+                "b" => b = Some(map.next_value()?),
+                "a" => a = Some(map.next_value()?),
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+        let r = r.ok_or_else(|| de::Error::missing_field("r"))?;
+        let g = g.ok_or_else(|| de::Error::missing_field("g"))?;
+        let b = b.ok_or_else(|| de::Error::missing_field("b"))?;
+        Ok(Color::new(r, g, b, a.unwrap_or(1.)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Theme {
+        hex: Color,
+        #[serde(with = "css")]
+        css: Color,
+        #[serde(with = "rgba")]
+        rgba: Color,
+        #[serde(with = "array")]
+        array: Color,
+        #[serde(with = "design_token")]
+        token: Color,
+    }
+
+    #[test]
+    fn test_serialize() {
+        let color = Color::new(255, 128, 0, 0.5);
+        let theme = Theme {
+            hex: color,
+            css: color,
+            rgba: color,
+            array: color,
+            token: color,
+        };
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(
+            json,
+            r##"{"hex":"#ff800080","css":"rgb(255 128 0 / 0.5)","rgba":{"r":255,"g":128,"b":0,"a":0.5},"array":[255,128,0,0.5],"token":{"colorSpace":"srgb","components":[1.0,0.5019607843137255,0.0],"alpha":0.5,"hex":"#ff8000"}}"##
+        );
+        // the hex alpha is quantized to a byte
+        let expected = Theme {
+            hex: Color::from("#ff800080"),
+            ..theme
+        };
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let color = |json: &str| serde_json::from_str::<Color>(json);
+        let red = Color::new(255, 0, 0, 1.);
+        assert_eq!(color(r#""red""#).unwrap(), red);
+        assert_eq!(color(r#""hsl(0 100% 50%)""#).unwrap(), red);
+        assert!(color(r#"{"r": 255, "g": 0, "b": 0}"#).is_err());
+        assert!(color("[255, 0, 0]").is_err());
+
+        let err = color(r#""bread""#).unwrap_err();
+        assert!(err.to_string().contains("Color not found: `bread`"));
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Css(#[serde(with = "css")] Color);
+    #[derive(Debug, Deserialize)]
+    struct Rgba(#[serde(with = "rgba")] Color);
+    #[derive(Debug, Deserialize)]
+    struct Array(#[serde(with = "array")] Color);
+
+    #[test]
+    fn test_deserialize_with() {
+        let red = Color::new(255, 0, 0, 1.);
+        let rgba = |json: &str| serde_json::from_str::<Rgba>(json).map(|c| c.0);
+        let array = |json: &str| serde_json::from_str::<Array>(json).map(|c| c.0);
+        assert_eq!(rgba(r#"{"r": 255, "g": 0, "b": 0}"#).unwrap(), red);
+        assert_eq!(array("[255, 0, 0]").unwrap(), red);
+        assert!(rgba(r#""red""#).is_err());
+        assert!(rgba(r#"{"r": 255, "g": 0}"#).is_err());
+        assert!(rgba(r#"{"r": 255, "g": 0, "b": 0, "x": 0}"#).is_err());
+        assert!(array("[255, 0]").is_err());
+        assert!(array("[256, 0, 0]").is_err());
+
+        // css accepts any representation
+        for json in [r#""red""#, r#"{"r": 255, "g": 0, "b": 0}"#, "[255, 0, 0]"] {
+            assert_eq!(serde_json::from_str::<Css>(json).unwrap().0, red);
+        }
+    }
+}
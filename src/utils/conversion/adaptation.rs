@@ -0,0 +1,110 @@
+use super::matrix::{self, Matrix3};
+use super::white_point::WhitePoint;
+
+/// Chromatic adaptation transform (CAT), used to convert XYZ values between
+/// white points.
+///
+/// <http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html>
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Adaptation {
+    #[default]
+    Bradford,
+    VonKries,
+    Cat02,
+    Cat16,
+}
+
+impl Adaptation {
+    /// The matrix from XYZ to the cone response domain.
+    fn cone_response(&self) -> Matrix3 {
+        match self {
+            Adaptation::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Adaptation::VonKries => [
+                [0.40024, 0.70760, -0.08081],
+                [-0.22630, 1.16532, 0.04570],
+                [0., 0., 0.91822],
+            ],
+            Adaptation::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            Adaptation::Cat16 => [
+                [0.401288, 0.650173, -0.051461],
+                [-0.250268, 1.204414, 0.045854],
+                [-0.002079, 0.048952, 0.953127],
+            ],
+        }
+    }
+
+    /// Adapt XYZ values seen under the `from` white to the `to` white.
+    ///
+    /// ```
+    /// use chroma_rust::{Adaptation, WhitePoint};
+    ///
+    /// let (x, y, z) = Adaptation::Bradford.adapt((0.95047, 1., 1.08883), WhitePoint::D65, WhitePoint::D50);
+    /// assert!((x - 0.96422).abs() < 1e-4);
+    /// assert!((y - 1.).abs() < 1e-4);
+    /// assert!((z - 0.82521).abs() < 1e-4);
+    /// ```
+    pub fn adapt(&self, xyz: (f64, f64, f64), from: WhitePoint, to: WhitePoint) -> (f64, f64, f64) {
+        if from == to {
+            return xyz;
+        }
+        matrix::transform(&self.matrix(from, to), xyz)
+    }
+
+    /// The XYZ to XYZ matrix adapting from the `from` white to the `to` white.
+    pub(crate) fn matrix(&self, from: WhitePoint, to: WhitePoint) -> Matrix3 {
+        let m = self.cone_response();
+        let (rs, gs, bs) = matrix::transform(&m, from.xyz());
+        let (rd, gd, bd) = matrix::transform(&m, to.xyz());
+        let scale = [[rd / rs, 0., 0.], [0., gd / gs, 0.], [0., 0., bd / bs]];
+        matrix::multiply(&matrix::invert(&m), &matrix::multiply(&scale, &m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4 && (a.2 - b.2).abs() < 1e-4
+    }
+
+    #[test]
+    fn test_adapt_white() {
+        for method in [
+            Adaptation::Bradford,
+            Adaptation::VonKries,
+            Adaptation::Cat02,
+            Adaptation::Cat16,
+        ] {
+            let white = method.adapt(WhitePoint::D65.xyz(), WhitePoint::D65, WhitePoint::A);
+            assert!(approx_equal(white, WhitePoint::A.xyz()));
+        }
+    }
+
+    #[test]
+    fn test_adapt_bradford() {
+        // sRGB red, D65 -> D50
+        let xyz = Adaptation::Bradford.adapt(
+            (0.4124564, 0.2126729, 0.0193339),
+            WhitePoint::D65,
+            WhitePoint::D50,
+        );
+        assert!(approx_equal(xyz, (0.4360747, 0.2225045, 0.0139322)));
+    }
+
+    #[test]
+    fn test_adapt_round_trip() {
+        let xyz = (0.3, 0.4, 0.5);
+        let there = Adaptation::Cat16.adapt(xyz, WhitePoint::D65, WhitePoint::F11);
+        let back = Adaptation::Cat16.adapt(there, WhitePoint::F11, WhitePoint::D65);
+        assert!(approx_equal(xyz, back));
+    }
+}
@@ -0,0 +1,331 @@
+use core::f64::consts::PI;
+
+use super::lab::lab2xyz;
+use super::matrix::{self, Matrix3};
+use super::white_point::WhitePoint;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+static M16: Matrix3 = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+/// The surround of the viewing conditions, i.e. the luminance around the
+/// viewed field relative to its white.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Surround {
+    /// Viewing a film projected in a dark room
+    Dark,
+    /// Viewing television
+    Dim,
+    /// Viewing surface colors, e.g. a screen in an office
+    #[default]
+    Average,
+}
+
+impl Surround {
+    /// The factors `F`, `c` and `Nc`.
+    fn factors(&self) -> (f64, f64, f64) {
+        match self {
+            Surround::Dark => (0.8, 0.525, 0.8),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Average => (1.0, 0.69, 1.0),
+        }
+    }
+}
+
+/// Viewing conditions of the CAM16 color appearance model.
+///
+/// The default matches the one of Material Design: a D65 white, an adapting
+/// luminance of about 11.72 cd/m² and a mid gray background.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ViewingConditions {
+    pub white: WhitePoint,
+    /// Luminance of the adapting field, in cd/m²
+    pub adapting_luminance: f64,
+    /// Relative luminance of the background, in the range [0, 100]
+    pub background_luminance: f64,
+    pub surround: Surround,
+    /// Whether the illuminant is fully discounted
+    pub discounting: bool,
+}
+
+impl Default for ViewingConditions {
+    fn default() -> Self {
+        let mid_gray = lab2xyz((50., 0., 0.), WhitePoint::D65).1 * 100.;
+        ViewingConditions {
+            white: WhitePoint::D65,
+            adapting_luminance: 200. / PI * mid_gray / 100.,
+            background_luminance: mid_gray,
+            surround: Surround::Average,
+            discounting: false,
+        }
+    }
+}
+
+/// Values derived from the viewing conditions.
+pub(crate) struct Environment {
+    n: f64,
+    aw: f64,
+    nbb: f64,
+    ncb: f64,
+    c: f64,
+    nc: f64,
+    z: f64,
+    fl: f64,
+    fl_root: f64,
+    rgb_d: [f64; 3],
+}
+
+impl Environment {
+    pub(crate) fn new(vc: &ViewingConditions) -> Environment {
+        let (xw, yw, zw) = vc.white.xyz();
+        let white = (xw * 100., yw * 100., zw * 100.);
+        let (rw, gw, bw) = matrix::transform(&M16, white);
+
+        let (f, c, nc) = vc.surround.factors();
+        let la = vc.adapting_luminance;
+        let d = if vc.discounting {
+            1.
+        } else {
+            (f * (1. - (1. / 3.6) * ((-la - 42.) / 92.).exp())).clamp(0., 1.)
+        };
+        let rgb_d = [rw, gw, bw].map(|w| d * (100. / w) + 1. - d);
+
+        let k = 1. / (5. * la + 1.);
+        let k4 = k.powi(4);
+        let fl = k4 * la + 0.1 * (1. - k4).powi(2) * (5. * la).cbrt();
+        let n = vc.background_luminance / white.1;
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 / n.powf(0.2);
+
+        let [ra, ga, ba] = [rw * rgb_d[0], gw * rgb_d[1], bw * rgb_d[2]].map(|v| adapt(v, fl));
+        let aw = (2. * ra + ga + 0.05 * ba) * nbb;
+
+        Environment {
+            n,
+            aw,
+            nbb,
+            ncb: nbb,
+            c,
+            nc,
+            z,
+            fl,
+            fl_root: fl.powf(0.25),
+            rgb_d,
+        }
+    }
+}
+
+/// Post-adaptation non-linear response compression
+fn adapt(v: f64, fl: f64) -> f64 {
+    let af = (fl * v.abs() / 100.).powf(0.42);
+    v.signum() * 400. * af / (af + 27.13)
+}
+
+fn unadapt(v: f64, fl: f64) -> f64 {
+    let base = (27.13 * v.abs() / (400. - v.abs())).max(0.);
+    v.signum() * 100. / fl * base.powf(1. / 0.42)
+}
+
+/// A color in the CAM16 color appearance model.
+///
+/// <https://doi.org/10.1002/col.22131>
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cam16 {
+    /// Lightness
+    pub j: f64,
+    /// Chroma
+    pub c: f64,
+    /// Hue angle, in degrees
+    pub h: f64,
+    /// Colorfulness
+    pub m: f64,
+    /// Saturation
+    pub s: f64,
+    /// Brightness
+    pub q: f64,
+}
+
+impl Cam16 {
+    /// Construct from CIE XYZ (the white has `y == 1`) seen in the viewing conditions.
+    pub fn from_xyz(xyz: (f64, f64, f64), vc: &ViewingConditions) -> Cam16 {
+        let env = Environment::new(vc);
+        let (x, y, z) = xyz;
+        let (r, g, b) = matrix::transform(&M16, (x * 100., y * 100., z * 100.));
+        let ra = adapt(r * env.rgb_d[0], env.fl);
+        let ga = adapt(g * env.rgb_d[1], env.fl);
+        let ba = adapt(b * env.rgb_d[2], env.fl);
+
+        let a = (11. * ra - 12. * ga + ba) / 11.;
+        let b = (ra + ga - 2. * ba) / 9.;
+        let u = (20. * ra + 20. * ga + 21. * ba) / 20.;
+        let p2 = (40. * ra + 20. * ga + ba) / 20.;
+
+        let h = b.atan2(a).to_degrees().rem_euclid(360.);
+        let ac = p2 * env.nbb;
+        let j = 100. * (ac / env.aw).powf(env.c * env.z);
+
+        let hue_prime = if h < 20.14 { h + 360. } else { h };
+        let e_hue = 0.25 * ((hue_prime.to_radians() + 2.).cos() + 3.8);
+        let p1 = 50000. / 13. * e_hue * env.nc * env.ncb;
+        let t = p1 * a.hypot(b) / (u + 0.305);
+        let alpha = t.powf(0.9) * (1.64 - 0.29_f64.powf(env.n)).powf(0.73);
+
+        Cam16::from_alpha(j, alpha, h, &env)
+    }
+
+    /// Construct from the lightness `j`, the chroma `c` and the hue `h`.
+    pub fn from_jch(j: f64, c: f64, h: f64, vc: &ViewingConditions) -> Cam16 {
+        Cam16::from_jch_in(j, c, h, &Environment::new(vc))
+    }
+
+    pub(crate) fn from_jch_in(j: f64, c: f64, h: f64, env: &Environment) -> Cam16 {
+        let alpha = if j == 0. { 0. } else { c / (j / 100.).sqrt() };
+        Cam16::from_alpha(j, alpha, h.rem_euclid(360.), env)
+    }
+
+    /// Construct from CAM16-UCS `J'`, `a'` and `b'`.
+    pub fn from_ucs(jab: (f64, f64, f64), vc: &ViewingConditions) -> Cam16 {
+        let (jstar, astar, bstar) = jab;
+        let env = Environment::new(vc);
+        let m = (astar.hypot(bstar) * 0.0228).exp_m1() / 0.0228;
+        let c = m / env.fl_root;
+        let h = bstar.atan2(astar).to_degrees();
+        let j = jstar / (1. - (jstar - 100.) * 0.007);
+        Cam16::from_jch_in(j, c, h, &env)
+    }
+
+    fn from_alpha(j: f64, alpha: f64, h: f64, env: &Environment) -> Cam16 {
+        let c = alpha * (j / 100.).sqrt();
+        let m = c * env.fl_root;
+        let s = 50. * (alpha * env.c / (env.aw + 4.)).sqrt();
+        let q = 4. / env.c * (j / 100.).sqrt() * (env.aw + 4.) * env.fl_root;
+        Cam16 { j, c, h, m, s, q }
+    }
+
+    /// CAM16-UCS `J'`, `a'` and `b'`, a uniform color space.
+    pub fn ucs(&self) -> (f64, f64, f64) {
+        let jstar = 1.7 * self.j / (1. + 0.007 * self.j);
+        let mstar = (0.0228 * self.m).ln_1p() / 0.0228;
+        let h = self.h.to_radians();
+        (jstar, mstar * h.cos(), mstar * h.sin())
+    }
+
+    /// Color difference in CAM16-UCS.
+    pub fn distance(&self, other: &Cam16) -> f64 {
+        let (j1, a1, b1) = self.ucs();
+        let (j2, a2, b2) = other.ucs();
+        let d = ((j1 - j2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt();
+        1.41 * d.powf(0.63)
+    }
+
+    /// Convert to CIE XYZ (the white has `y == 1`) seen in the viewing conditions.
+    pub fn xyz(&self, vc: &ViewingConditions) -> (f64, f64, f64) {
+        self.xyz_in(&Environment::new(vc))
+    }
+
+    pub(crate) fn xyz_in(&self, env: &Environment) -> (f64, f64, f64) {
+        let alpha = if self.c == 0. || self.j == 0. {
+            0.
+        } else {
+            self.c / (self.j / 100.).sqrt()
+        };
+        let t = (alpha / (1.64 - 0.29_f64.powf(env.n)).powf(0.73)).powf(1. / 0.9);
+        let h = self.h.to_radians();
+
+        let e_hue = 0.25 * ((h + 2.).cos() + 3.8);
+        let ac = env.aw * (self.j / 100.).powf(1. / env.c / env.z);
+        let p1 = e_hue * (50000. / 13.) * env.nc * env.ncb;
+        let p2 = ac / env.nbb;
+
+        let gamma = 23. * (p2 + 0.305) * t / (23. * p1 + 11. * t * h.cos() + 108. * t * h.sin());
+        let a = gamma * h.cos();
+        let b = gamma * h.sin();
+        let ra = (460. * p2 + 451. * a + 288. * b) / 1403.;
+        let ga = (460. * p2 - 891. * a - 261. * b) / 1403.;
+        let ba = (460. * p2 - 220. * a - 6300. * b) / 1403.;
+
+        let rgb = (
+            unadapt(ra, env.fl) / env.rgb_d[0],
+            unadapt(ga, env.fl) / env.rgb_d[1],
+            unadapt(ba, env.fl) / env.rgb_d[2],
+        );
+        let (x, y, z) = matrix::transform(&matrix::invert(&M16), rgb);
+        (x / 100., y / 100., z / 100.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::conversion::xyz::rgb2xyz;
+
+    fn approx_equal(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.05
+    }
+
+    // Values from material-color-utilities
+    #[test]
+    fn test_from_xyz() {
+        let vc = ViewingConditions::default();
+
+        let cam = Cam16::from_xyz(rgb2xyz((255, 0, 0)), &vc);
+        assert!(approx_equal(cam.h, 27.408));
+        assert!(approx_equal(cam.c, 113.357));
+        assert!(approx_equal(cam.j, 46.445));
+        assert!(approx_equal(cam.m, 89.494));
+        assert!(approx_equal(cam.s, 91.889));
+        assert!(approx_equal(cam.q, 105.988));
+
+        let cam = Cam16::from_xyz(rgb2xyz((0, 0, 255)), &vc);
+        assert!(approx_equal(cam.h, 282.788));
+        assert!(approx_equal(cam.c, 87.230));
+        assert!(approx_equal(cam.j, 25.465));
+
+        let cam = Cam16::from_xyz(rgb2xyz((0, 0, 0)), &vc);
+        assert_eq!((cam.j, cam.c, cam.m, cam.s, cam.q), (0., 0., 0., 0., 0.));
+    }
+
+    #[test]
+    fn test_xyz_round_trip() {
+        let vc = ViewingConditions {
+            surround: Surround::Dim,
+            background_luminance: 10.,
+            ..Default::default()
+        };
+        for xyz in [
+            (0.2, 0.3, 0.4),
+            (0.4125, 0.2127, 0.0193),
+            (0.9505, 1., 1.089),
+        ] {
+            let (x, y, z) = Cam16::from_xyz(xyz, &vc).xyz(&vc);
+            assert!((x - xyz.0).abs() < 1e-6);
+            assert!((y - xyz.1).abs() < 1e-6);
+            assert!((z - xyz.2).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_from_jch() {
+        let vc = ViewingConditions::default();
+        let cam = Cam16::from_xyz((0.2, 0.3, 0.4), &vc);
+        assert_eq!(
+            Cam16::from_jch(cam.j, cam.c, cam.h, &vc).xyz(&vc),
+            cam.xyz(&vc)
+        );
+    }
+
+    #[test]
+    fn test_ucs() {
+        let vc = ViewingConditions::default();
+        let cam = Cam16::from_xyz((0.2, 0.3, 0.4), &vc);
+        let from_ucs = Cam16::from_ucs(cam.ucs(), &vc);
+        assert!((from_ucs.j - cam.j).abs() < 1e-9);
+        assert!((from_ucs.c - cam.c).abs() < 1e-9);
+        assert!((from_ucs.h - cam.h).abs() < 1e-9);
+        assert_eq!(cam.distance(&cam), 0.);
+    }
+}
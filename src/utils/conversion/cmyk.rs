@@ -1,3 +1,5 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
 use crate::utils::round;
 
 pub fn rgb2cmyk(color: (u8, u8, u8)) -> (f64, f64, f64, f64) {
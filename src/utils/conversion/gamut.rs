@@ -0,0 +1,148 @@
+use super::lch::{lab2lch, lch2lab};
+use super::oklab::{oklab2xyz, xyz2oklab};
+use super::rgb_space::RgbSpace;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+// Just noticeable difference in OKLab, and the tolerance of the chroma search
+static JND: f64 = 0.02;
+static EPSILON: f64 = 0.0001;
+
+/// How to bring a color outside of a gamut into it.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum GamutMapping {
+    /// Clamp each channel to `0..1`, which is fast but may shift the hue.
+    Clip,
+    /// The CSS Color 4 algorithm, reducing the OKLCh chroma until the clipped
+    /// color is within a just noticeable difference.
+    ///
+    /// <https://www.w3.org/TR/css-color-4/#binsearch>
+    #[default]
+    Css,
+}
+
+/// Whether the channels of an RGB color space are within its gamut.
+pub fn in_gamut(rgb: (f64, f64, f64)) -> bool {
+    let (r, g, b) = rgb;
+    [r, g, b]
+        .iter()
+        .all(|v| (-EPSILON..=1. + EPSILON).contains(v))
+}
+
+/// Map an OKLCh color into the gamut of an RGB color space.
+///
+/// Returns the channels in the given space, all in the range `0..1`.
+pub fn map_oklch(
+    oklch: (f64, f64, f64),
+    space: &RgbSpace,
+    method: GamutMapping,
+) -> (f64, f64, f64) {
+    let to_rgb = |oklch: (f64, f64, f64)| space.from_xyz(oklab2xyz(lch2lab(oklch)));
+    let origin = to_rgb(oklch);
+    if method == GamutMapping::Clip || in_gamut(origin) {
+        return clip(origin);
+    }
+
+    let (l, c, h) = oklch;
+    if l >= 1. {
+        return (1., 1., 1.);
+    }
+    if l <= 0. {
+        return (0., 0., 0.);
+    }
+
+    let delta_e = |rgb: (f64, f64, f64), oklch: (f64, f64, f64)| {
+        let (l1, a1, b1) = xyz2oklab(space.to_xyz(rgb));
+        let (l2, a2, b2) = lch2lab(oklch);
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    };
+
+    let mut clipped = clip(origin);
+    if delta_e(clipped, oklch) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.;
+    let mut max = c;
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.;
+        let current = (l, chroma, h);
+        let rgb = to_rgb(current);
+        if min_in_gamut && in_gamut(rgb) {
+            min = chroma;
+            continue;
+        }
+        clipped = clip(rgb);
+        let e = delta_e(clipped, current);
+        if e < JND {
+            if JND - e < EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+/// Map a CIE XYZ (D65) color into the gamut of an RGB color space.
+pub fn map_xyz(xyz: (f64, f64, f64), space: &RgbSpace, method: GamutMapping) -> (f64, f64, f64) {
+    map_oklch(lab2lch(xyz2oklab(xyz)), space, method)
+}
+
+fn clip(rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        rgb.0.clamp(0., 1.),
+        rgb.1.clamp(0., 1.),
+        rgb.2.clamp(0., 1.),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_gamut() {
+        assert!(in_gamut((0., 0.5, 1.)));
+        assert!(!in_gamut((-0.1, 0.5, 1.)));
+        assert!(!in_gamut((0., 0.5, 1.1)));
+    }
+
+    #[test]
+    fn test_map_oklch_in_gamut() {
+        // oklch of sRGB red
+        let (r, g, b) = map_oklch((0.628, 0.2577, 29.23), &RgbSpace::SRGB, GamutMapping::Css);
+        assert!((r - 1.).abs() < 0.001);
+        assert!(g.abs() < 0.001);
+        assert!(b.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_map_oklch_out_of_gamut() {
+        let oklch = (0.7, 0.4, 150.);
+
+        let mapped = map_oklch(oklch, &RgbSpace::SRGB, GamutMapping::Css);
+        assert!(in_gamut(mapped));
+        let (_, c, h) = lab2lch(xyz2oklab(RgbSpace::SRGB.to_xyz(mapped)));
+        assert!(c < 0.4);
+
+        let clipped = map_oklch(oklch, &RgbSpace::SRGB, GamutMapping::Clip);
+        assert!(in_gamut(clipped));
+        let (_, _, clipped_h) = lab2lch(xyz2oklab(RgbSpace::SRGB.to_xyz(clipped)));
+        // the hue is better preserved than by clipping
+        assert!((h - 150.).abs() < 4.);
+        assert!((h - 150.).abs() < (clipped_h - 150.).abs());
+    }
+
+    #[test]
+    fn test_map_oklch_lightness() {
+        let white = map_oklch((1.2, 0.3, 20.), &RgbSpace::SRGB, GamutMapping::Css);
+        assert_eq!(white, (1., 1., 1.));
+        let black = map_oklch((-0.1, 0.3, 20.), &RgbSpace::SRGB, GamutMapping::Css);
+        assert_eq!(black, (0., 0., 0.));
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+/// RGB to HCG (hue, chroma, grayness) color conversion
+///
+/// <https://github.com/acterhd/hcg-color>
+///
+/// The chroma and grayness are in the range [0, 1].
+pub fn rgb2hcg(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let r = color.0 as f64 / 255.;
+    let g = color.1 as f64 / 255.;
+    let b = color.2 as f64 / 255.;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let mut h = 0.;
+    if delta != 0. {
+        h = match max {
+            x if x == r => (g - b) / delta,
+            x if x == g => 2. + (b - r) / delta,
+            _ => 4. + (r - g) / delta,
+        } * 60.;
+        if h < 0. {
+            h += 360.;
+        }
+    }
+    let gray = if delta < 1. { min / (1. - delta) } else { 0. };
+
+    (h, delta, gray)
+}
+
+/// HCG to RGB color conversion
+pub fn hcg2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (h, c, gray) = color;
+
+    let (r, g, b) = if c == 0. {
+        (gray, gray, gray)
+    } else {
+        let h = h.rem_euclid(360.) / 60.;
+        let f = h - h.floor();
+        let p = gray * (1. - c);
+        let q = p + c * (1. - f);
+        let t = p + c * f;
+        let v = p + c;
+        match h.floor() as u8 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        }
+    };
+
+    (
+        (r * 255.).round() as u8,
+        (g * 255.).round() as u8,
+        (b * 255.).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb2hcg() {
+        assert_eq!(rgb2hcg((255, 0, 0)), (0., 1., 0.));
+        assert_eq!(rgb2hcg((0, 0, 255)), (240., 1., 0.));
+        assert_eq!(rgb2hcg((255, 255, 255)), (0., 0., 1.));
+        assert_eq!(rgb2hcg((0, 0, 0)), (0., 0., 0.));
+
+        let (h, c, g) = rgb2hcg((191, 64, 64));
+        assert_eq!(h, 0.);
+        assert!((c - 0.498).abs() < 0.001);
+        assert!((g - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_hcg2rgb() {
+        assert_eq!(hcg2rgb((0., 1., 0.)), (255, 0, 0));
+        assert_eq!(hcg2rgb((120., 1., 0.)), (0, 255, 0));
+        assert_eq!(hcg2rgb((0., 0., 0.5)), (128, 128, 128));
+        assert_eq!(hcg2rgb((360., 1., 0.)), (255, 0, 0));
+        assert_eq!(hcg2rgb(rgb2hcg((119, 96, 191))), (119, 96, 191));
+    }
+}
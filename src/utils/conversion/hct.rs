@@ -0,0 +1,148 @@
+use super::cam16::{Cam16, Environment, ViewingConditions};
+use super::gamut::in_gamut;
+use super::lab::{lab2xyz, xyz2lab};
+use super::rgb_space::RgbSpace;
+use super::white_point::WhitePoint;
+use super::xyz::{rgb2xyz, xyz2rgb};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+// Iterations of the bisections on the CAM16 lightness and chroma
+static J_ITERATIONS: usize = 40;
+static CHROMA_ITERATIONS: usize = 20;
+
+/// Convert RGB to HCT (hue, chroma, tone)
+///
+/// The hue and chroma are the ones of CAM16, the tone is the CIE L*.
+///
+/// <https://material.io/blog/science-of-color-design>
+pub fn rgb2hct(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let xyz = rgb2xyz(color);
+    let cam = Cam16::from_xyz(xyz, &ViewingConditions::default());
+    (cam.h, cam.c, tone(color))
+}
+
+/// Convert HCT to RGB
+///
+/// When the color is out of the sRGB gamut, the chroma is reduced while
+/// keeping the hue and the tone.
+pub fn hct2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (hue, chroma, tone) = color;
+    let y = lab2xyz((tone.clamp(0., 100.), 0., 0.), WhitePoint::D65).1;
+    if chroma <= 0. || y <= 0. || y >= 1. {
+        return gray(y);
+    }
+    let env = Environment::new(&ViewingConditions::default());
+    if let Some(rgb) = solve(hue, chroma, y, &env) {
+        return encode(rgb);
+    }
+
+    let mut low = 0.;
+    let mut high = chroma;
+    let mut best = None;
+    for _ in 0..CHROMA_ITERATIONS {
+        let mid = (low + high) / 2.;
+        match solve(hue, mid, y, &env) {
+            Some(rgb) => {
+                best = Some(rgb);
+                low = mid;
+            }
+            None => high = mid,
+        }
+    }
+    best.map_or_else(|| gray(y), encode)
+}
+
+/// Find the CAM16 lightness giving the luminance `y` for the hue and chroma,
+/// returning the sRGB channels in `0..1` if they're in gamut.
+fn solve(hue: f64, chroma: f64, y: f64, env: &Environment) -> Option<(f64, f64, f64)> {
+    let mut low = 0.;
+    let mut high = 100.;
+    let mut xyz = (0., 0., 0.);
+    for _ in 0..J_ITERATIONS {
+        let mid = (low + high) / 2.;
+        xyz = Cam16::from_jch_in(mid, chroma, hue, env).xyz_in(env);
+        if xyz.1 < y {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    let rgb = RgbSpace::SRGB.from_xyz(xyz);
+    if (xyz.1 - y).abs() < 1e-4 && in_gamut(rgb) {
+        Some(rgb)
+    } else {
+        None
+    }
+}
+
+fn encode(rgb: (f64, f64, f64)) -> (u8, u8, u8) {
+    let channel = |v: f64| (v.clamp(0., 1.) * 255.).round() as u8;
+    (channel(rgb.0), channel(rgb.1), channel(rgb.2))
+}
+
+fn tone(color: (u8, u8, u8)) -> f64 {
+    xyz2lab(rgb2xyz(color), WhitePoint::D65).0
+}
+
+fn gray(y: f64) -> (u8, u8, u8) {
+    let (xn, _, zn) = WhitePoint::D65.xyz();
+    xyz2rgb((xn * y, y, zn * y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.05
+    }
+
+    #[test]
+    fn test_rgb2hct() {
+        let (h, c, t) = rgb2hct((255, 0, 0));
+        assert!(approx_equal(h, 27.408));
+        assert!(approx_equal(c, 113.357));
+        assert!(approx_equal(t, 53.237));
+
+        let (_, c, t) = rgb2hct((255, 255, 255));
+        assert!(c < 3.);
+        assert!(approx_equal(t, 100.));
+    }
+
+    #[test]
+    fn test_hct2rgb() {
+        for rgb in [
+            (255, 0, 0),
+            (0, 255, 0),
+            (0, 0, 255),
+            (66, 133, 244),
+            (250, 200, 10),
+        ] {
+            assert_eq!(hct2rgb(rgb2hct(rgb)), rgb);
+        }
+        assert_eq!(hct2rgb((120., 0., 50.)), (119, 119, 119));
+        assert_eq!(hct2rgb((120., 40., 100.)), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_hct2rgb_out_of_gamut() {
+        let rgb = hct2rgb((282.788, 200., 50.));
+        let (h, c, t) = rgb2hct(rgb);
+        assert!((h - 282.788).abs() < 2.);
+        assert!(c < 200.);
+        assert!((t - 50.).abs() < 1.);
+    }
+
+    #[test]
+    fn test_hct2rgb_low_tone() {
+        for hue in [0., 90., 150., 282.788] {
+            for tone in [1., 2., 3., 5.] {
+                let (h, _, t) = rgb2hct(hct2rgb((hue, 30., tone)));
+                let dh = (h - hue + 180.).rem_euclid(360.) - 180.;
+                assert!(dh.abs() < 10., "hue {} for {} at tone {}", h, hue, tone);
+                assert!((t - tone).abs() < 0.15, "tone {} for {}", t, tone);
+            }
+        }
+    }
+}
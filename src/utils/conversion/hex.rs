@@ -1,38 +1,112 @@
-use crate::utils::round;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::utils::parser::ParseColorError;
+
+/// When to write the alpha channel in a hex string.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum HexAlpha {
+    /// Only when it's less than `ff` once written as a byte
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// How to format a hex string, see [`Color::hex_with`](crate::Color::hex_with).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HexOptions {
+    pub alpha: HexAlpha,
+    /// Write `#rgb` or `#rgba` when every channel has two identical digits
+    pub short: bool,
+    pub uppercase: bool,
+    /// Written before the digits, e.g. `#`, `0x` or nothing
+    pub prefix: &'static str,
+}
+
+impl Default for HexOptions {
+    fn default() -> Self {
+        HexOptions {
+            alpha: HexAlpha::Auto,
+            short: false,
+            uppercase: false,
+            prefix: "#",
+        }
+    }
+}
 
 pub fn rgb2hex(color: (u8, u8, u8, f64)) -> String {
+    rgb2hex_with(color, &HexOptions::default())
+}
+
+pub fn rgb2hex_with(color: (u8, u8, u8, f64), options: &HexOptions) -> String {
     let (r, g, b, a) = color;
-    if a == 1. {
-        format!("#{:02x}{:02x}{:02x}", r, g, b)
-    } else {
-        let a = (a * 255.).round() as u8;
-        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    let mut channels = vec![r, g, b];
+    let alpha = (a * 255.).round() as u8;
+    let with_alpha = match options.alpha {
+        HexAlpha::Auto => alpha != 255,
+        HexAlpha::Always => true,
+        HexAlpha::Never => false,
+    };
+    if with_alpha {
+        channels.push(alpha);
     }
+
+    let short = options.short && channels.iter().all(|c| c >> 4 == c & 0xf);
+    let digits: String = channels
+        .iter()
+        .map(|c| {
+            if short {
+                format!("{:x}", c & 0xf)
+            } else {
+                format!("{:02x}", c)
+            }
+        })
+        .collect();
+    let digits = if options.uppercase {
+        digits.to_uppercase()
+    } else {
+        digits
+    };
+    format!("{}{}", options.prefix, digits)
 }
 
-pub fn hex2rgb(hex: &str) -> (u8, u8, u8, f64) {
-    let mut hex = String::from(hex);
-    let mut alpha = 1.;
-    let len = hex.len();
-    if len == 4 {
-        let mut hex_new = String::from("#");
-        for c in hex[1..].chars() {
-            hex_new.push(c);
-            hex_new.push(c);
-        }
-        hex = hex_new;
-    } else if len == 9 {
-        // #rrggbbaa
-        alpha = (u8::from_str_radix(&hex[7..], 16).unwrap()) as f64 / 255.;
-        alpha = round(alpha, 2);
-        hex = hex[..7].to_string();
-    }
+/// Strip the `#` or `0x` prefix of a hex string, if any.
+fn hex_digits(hex: &str) -> &str {
+    hex.strip_prefix('#')
+        .or_else(|| hex.strip_prefix("0x"))
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex)
+}
+
+/// Check if a string is a hex color: 3, 4, 6 or 8 digits after an optional `#` or `0x`.
+pub fn is_hex(hex: &str) -> bool {
+    let digits = hex_digits(hex);
+    matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
 
-    let r = u8::from_str_radix(&hex[1..3], 16).unwrap();
-    let g = u8::from_str_radix(&hex[3..5], 16).unwrap();
-    let b = u8::from_str_radix(&hex[5..7], 16).unwrap();
+/// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with a `#`, `0x` or no prefix.
+pub fn hex2rgb(hex: &str) -> Result<(u8, u8, u8, f64), ParseColorError> {
+    if !is_hex(hex) {
+        return Err(ParseColorError::InvalidHex(hex.to_string()));
+    }
+    let mut digits = hex_digits(hex).to_string();
+    if digits.len() <= 4 {
+        digits = digits.chars().flat_map(|c| [c, c]).collect();
+    }
+    let channel = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap();
 
-    (r, g, b, alpha)
+    let alpha = if digits.len() == 8 {
+        channel(3) as f64 / 255.
+    } else {
+        1.
+    };
+    Ok((channel(0), channel(1), channel(2), alpha))
 }
 
 #[cfg(test)]
@@ -46,17 +120,66 @@ mod tests {
 
         let color = (255, 255, 255, 0.5);
         assert_eq!(rgb2hex(color), "#ffffff80");
+
+        // the alpha is quantized to a byte before deciding to write it
+        assert_eq!(rgb2hex((255, 0, 0, 0.999)), "#ff0000");
+        assert_eq!(rgb2hex((255, 0, 0, 0.997)), "#ff0000fe");
+    }
+
+    #[test]
+    fn test_rgb2hex_with() {
+        let options = HexOptions {
+            short: true,
+            uppercase: true,
+            ..Default::default()
+        };
+        assert_eq!(rgb2hex_with((255, 0, 204, 1.), &options), "#F0C");
+        assert_eq!(rgb2hex_with((255, 0, 205, 1.), &options), "#FF00CD");
+        assert_eq!(rgb2hex_with((255, 0, 204, 0.6), &options), "#F0C9");
+
+        let options = HexOptions {
+            alpha: HexAlpha::Always,
+            prefix: "0x",
+            ..Default::default()
+        };
+        assert_eq!(rgb2hex_with((255, 0, 204, 1.), &options), "0xff00ccff");
+
+        let options = HexOptions {
+            alpha: HexAlpha::Never,
+            prefix: "",
+            ..Default::default()
+        };
+        assert_eq!(rgb2hex_with((255, 0, 204, 0.5), &options), "ff00cc");
     }
 
     #[test]
     fn test_hex2rgb() {
         let hex = "#ffffff";
-        assert_eq!(hex2rgb(hex), (255, 255, 255, 1.));
+        assert_eq!(hex2rgb(hex), Ok((255, 255, 255, 1.)));
 
         let hex = "#fff";
-        assert_eq!(hex2rgb(hex), (255, 255, 255, 1.));
+        assert_eq!(hex2rgb(hex), Ok((255, 255, 255, 1.)));
 
         let hex = "#ffffff80";
-        assert_eq!(hex2rgb(hex), (255, 255, 255, 0.5));
+        assert_eq!(hex2rgb(hex), Ok((255, 255, 255, 128. / 255.)));
+
+        assert_eq!(hex2rgb("#f0c8"), Ok((255, 0, 204, 136. / 255.)));
+        assert_eq!(hex2rgb("0xff00cc"), Ok((255, 0, 204, 1.)));
+        assert_eq!(hex2rgb("ff00cc80"), Ok((255, 0, 204, 128. / 255.)));
+        assert_eq!(
+            hex2rgb("#abcde"),
+            Err(ParseColorError::InvalidHex("#abcde".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_is_hex() {
+        assert!(is_hex("#abc"));
+        assert!(is_hex("#abcd"));
+        assert!(is_hex("0xaabbcc"));
+        assert!(is_hex("aabbccdd"));
+        assert!(!is_hex("#abcde"));
+        assert!(!is_hex("#ggg"));
+        assert!(!is_hex("red"));
     }
 }
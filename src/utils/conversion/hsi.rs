@@ -0,0 +1,80 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use core::f64::consts::PI;
+
+/// [RGB to HSI color conversion](https://en.wikipedia.org/wiki/HSL_and_HSV#Hue_and_chroma)
+///
+/// The saturation and intensity are in the range [0, 1].
+pub fn rgb2hsi(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let r = color.0 as f64 / 255.;
+    let g = color.1 as f64 / 255.;
+    let b = color.2 as f64 / 255.;
+
+    let min = r.min(g).min(b);
+    let i = (r + g + b) / 3.;
+    let s = if i > 0. { 1. - min / i } else { 0. };
+
+    let mut h = 0.;
+    if s != 0. {
+        let cos_h = ((r - g) + (r - b)) / 2. / ((r - g).powi(2) + (r - b) * (g - b)).sqrt();
+        h = cos_h.clamp(-1., 1.).acos();
+        if b > g {
+            h = 2. * PI - h;
+        }
+    }
+
+    (h.to_degrees(), s, i)
+}
+
+/// HSI to RGB color conversion
+pub fn hsi2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (h, s, i) = color;
+    let h = h.rem_euclid(360.) / 360.;
+
+    // the channel at the hue sector, the others are (1 - s) / 3 and the rest
+    let component = |h: f64| (1. + s * (2. * PI * h).cos() / (PI / 3. - 2. * PI * h).cos()) / 3.;
+    let (r, g, b) = if h < 1. / 3. {
+        let b = (1. - s) / 3.;
+        let r = component(h);
+        (r, 1. - (r + b), b)
+    } else if h < 2. / 3. {
+        let r = (1. - s) / 3.;
+        let g = component(h - 1. / 3.);
+        (r, g, 1. - (r + g))
+    } else {
+        let g = (1. - s) / 3.;
+        let b = component(h - 2. / 3.);
+        (1. - (g + b), g, b)
+    };
+
+    let channel = |v: f64| ((i * v * 3.).clamp(0., 1.) * 255.).round() as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb2hsi() {
+        assert_eq!(rgb2hsi((255, 255, 255)), (0., 0., 1.));
+        assert_eq!(rgb2hsi((0, 0, 0)), (0., 0., 0.));
+
+        let (h, s, i) = rgb2hsi((255, 0, 0));
+        assert!(h.abs() < 0.001);
+        assert!((s - 1.).abs() < 0.001);
+        assert!((i - 1. / 3.).abs() < 0.001);
+
+        let (h, _, _) = rgb2hsi((0, 0, 255));
+        assert!((h - 240.).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_hsi2rgb() {
+        assert_eq!(hsi2rgb((0., 1., 1. / 3.)), (255, 0, 0));
+        assert_eq!(hsi2rgb((120., 1., 1. / 3.)), (0, 255, 0));
+        assert_eq!(hsi2rgb((240., 1., 1. / 3.)), (0, 0, 255));
+        assert_eq!(hsi2rgb((0., 0., 0.5)), (128, 128, 128));
+        assert_eq!(hsi2rgb(rgb2hsi((119, 96, 191))), (119, 96, 191));
+    }
+}
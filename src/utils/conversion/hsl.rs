@@ -1,3 +1,6 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
 /// [RGB to HSL color conversion](https://www.rapidtables.com/convert/color/rgb-to-hsl.html)
 pub fn rgb2hsl(color: (u8, u8, u8)) -> (f64, f64, f64) {
     let (r, g, b) = color;
@@ -38,12 +41,12 @@ pub fn hsl2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
     let m = l - c / 2.0;
 
     let (r, g, b) = match h {
-        h if h >= 0.0 && h < 60.0 => (c, x, 0.0),
-        h if h >= 60.0 && h < 120.0 => (x, c, 0.0),
-        h if h >= 120.0 && h < 180.0 => (0.0, c, x),
-        h if h >= 180.0 && h < 240.0 => (0.0, x, c),
-        h if h >= 240.0 && h < 300.0 => (x, 0.0, c),
-        h if h >= 300.0 && h < 360.0 => (c, 0.0, x),
+        h if (0.0..60.0).contains(&h) => (c, x, 0.0),
+        h if (60.0..120.0).contains(&h) => (x, c, 0.0),
+        h if (120.0..180.0).contains(&h) => (0.0, c, x),
+        h if (180.0..240.0).contains(&h) => (0.0, x, c),
+        h if (240.0..300.0).contains(&h) => (x, 0.0, c),
+        h if (300.0..=360.0).contains(&h) => (c, 0.0, x),
         _ => panic!(),
     };
 
@@ -82,5 +85,7 @@ mod tests {
         let color = (0.0, 0.0, 0.0);
         let rgb = hsl2rgb(color);
         assert_eq!(rgb, (0, 0, 0));
+
+        assert_eq!(hsl2rgb((360.0, 1.0, 0.5)), (255, 0, 0));
     }
 }
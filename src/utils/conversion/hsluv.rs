@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+
+use super::lch::{lab2lch, lch2lab};
+use super::luv::{luv2rgb, rgb2luv, white_uv, KAPPA};
+use super::xyz::XYZ_RGB;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+/// Convert RGB to HSLuv
+///
+/// <https://www.hsluv.org/>
+///
+/// The hue is in degrees, the saturation and lightness in the range [0, 100].
+pub fn rgb2hsluv(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    lchuv2hsluv(luv2lch(rgb2luv(color)))
+}
+
+/// Convert HSLuv to RGB
+pub fn hsluv2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    luv2rgb(lch2lab(hsluv2lchuv(color)))
+}
+
+/// Convert RGB to HPLuv, the pastel variant of HSLuv
+///
+/// <https://www.hsluv.org/>
+///
+/// The saturation can exceed 100 for colors outside of the pastel range.
+pub fn rgb2hpluv(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    lchuv2hpluv(luv2lch(rgb2luv(color)))
+}
+
+/// Convert HPLuv to RGB
+pub fn hpluv2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    luv2rgb(lch2lab(hpluv2lchuv(color)))
+}
+
+/// Convert CIE LCh(uv) to HSLuv
+pub fn lchuv2hsluv(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, c, h) = color;
+    if l > 99.9999999 {
+        return (h, 0., 100.);
+    }
+    if l < 0.00000001 {
+        return (h, 0., 0.);
+    }
+    (h, c / max_chroma_for_lh(l, h) * 100., l)
+}
+
+/// Convert HSLuv to CIE LCh(uv)
+pub fn hsluv2lchuv(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (h, s, l) = color;
+    if l > 99.9999999 {
+        return (100., 0., h);
+    }
+    if l < 0.00000001 {
+        return (0., 0., h);
+    }
+    (l, max_chroma_for_lh(l, h) / 100. * s, h)
+}
+
+/// Convert CIE LCh(uv) to HPLuv
+pub fn lchuv2hpluv(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, c, h) = color;
+    if l > 99.9999999 {
+        return (h, 0., 100.);
+    }
+    if l < 0.00000001 {
+        return (h, 0., 0.);
+    }
+    (h, c / max_safe_chroma_for_l(l) * 100., l)
+}
+
+/// Convert HPLuv to CIE LCh(uv)
+pub fn hpluv2lchuv(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (h, s, l) = color;
+    if l > 99.9999999 {
+        return (100., 0., h);
+    }
+    if l < 0.00000001 {
+        return (0., 0., h);
+    }
+    (l, max_safe_chroma_for_l(l) / 100. * s, h)
+}
+
+/// Like `lab2lch`, but grays get a hue of 0 despite the rounding of the
+/// sRGB matrix and the white point leaving them a tiny chroma
+fn luv2lch(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, c, h) = lab2lch(color);
+    if c < 0.0001 {
+        (l, c, 0.)
+    } else {
+        (l, c, h)
+    }
+}
+
+/// The lines bounding the sRGB gamut at the lightness `l`, as `(slope, intercept)`
+/// in the u, v plane, where a linear channel `m1 * x + m2 * y + m3 * z` is 0 or 1
+fn bounds(l: f64) -> Vec<(f64, f64)> {
+    let y = if l <= 8. {
+        l / KAPPA
+    } else {
+        ((l + 16.) / 116.).powi(3)
+    };
+    let (un, vn) = white_uv();
+
+    let mut result = Vec::with_capacity(6);
+    for [m1, m2, m3] in XYZ_RGB {
+        for t in [0., 1.] {
+            let a = (9. * m1 - 3. * m3) * y;
+            let b = (4. * m2 - 20. * m3) * y - 4. * t;
+            let c = 13. * l * (a * un + b * vn + 12. * m3 * y);
+            result.push((-a / b, -c / b));
+        }
+    }
+    result
+}
+
+fn max_safe_chroma_for_l(l: f64) -> f64 {
+    bounds(l)
+        .into_iter()
+        .map(|(slope, intercept)| intercept.abs() / (slope * slope + 1.).sqrt())
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+    let h = h.to_radians();
+    bounds(l)
+        .into_iter()
+        .map(|(slope, intercept)| intercept / (h.sin() - slope * h.cos()))
+        .filter(|length| *length >= 0.)
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 0.01 && (a.1 - b.1).abs() < 0.01 && (a.2 - b.2).abs() < 0.01
+    }
+
+    // Values from the HSLuv reference snapshot
+    #[test]
+    fn test_rgb2hsluv() {
+        assert!(approx_equal(rgb2hsluv((255, 0, 0)), (12.177, 100., 53.237)));
+        assert!(approx_equal(
+            rgb2hsluv((0, 255, 0)),
+            (127.715, 100., 87.736)
+        ));
+        assert!(approx_equal(
+            rgb2hsluv((0, 0, 255)),
+            (265.874, 100., 32.301)
+        ));
+        assert!(approx_equal(rgb2hsluv((255, 255, 255)), (0., 0., 100.)));
+        assert!(approx_equal(rgb2hsluv((0, 0, 0)), (0., 0., 0.)));
+    }
+
+    /// Whether `a` is close to the reference value `b`, up to the constants of
+    /// `conversion::luv` differing from the reference implementation's
+    fn close_to_reference(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+        let hue_diff = ((a.0 - b.0 + 180.).rem_euclid(360.) - 180.).abs();
+        (b.1 < 0.01 || hue_diff < 0.02)
+            && (a.1 - b.1).abs() < 0.01_f64.max(b.1 * 0.001)
+            && (a.2 - b.2).abs() < 0.01
+    }
+
+    // Every 8th color of the HSLuv reference snapshot
+    #[test]
+    fn test_snapshot() {
+        for line in include_str!("fixtures/hsluv_snapshot.txt").lines() {
+            if line.starts_with("# ") {
+                continue;
+            }
+            let (hex, values) = line.split_once(' ').unwrap();
+            let v: Vec<f64> = values.split(' ').map(|v| v.parse().unwrap()).collect();
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+            let rgb = (channel(1), channel(3), channel(5));
+            let (hsluv, hpluv) = ((v[0], v[1], v[2]), (v[3], v[4], v[5]));
+
+            let near = |a: (u8, u8, u8)| {
+                a.0.abs_diff(rgb.0) <= 1 && a.1.abs_diff(rgb.1) <= 1 && a.2.abs_diff(rgb.2) <= 1
+            };
+
+            let actual = rgb2hsluv(rgb);
+            assert!(close_to_reference(actual, hsluv), "{}: {:?}", hex, actual);
+            assert_eq!(hsluv2rgb(actual), rgb, "{}", hex);
+            assert!(near(hsluv2rgb(hsluv)), "{}", hex);
+            let actual = rgb2hpluv(rgb);
+            assert!(close_to_reference(actual, hpluv), "{}: {:?}", hex, actual);
+            assert_eq!(hpluv2rgb(actual), rgb, "{}", hex);
+            assert!(near(hpluv2rgb(hpluv)), "{}", hex);
+        }
+    }
+
+    #[test]
+    fn test_hsluv2rgb() {
+        assert_eq!(hsluv2rgb((12.177, 100., 53.237)), (255, 0, 0));
+        assert_eq!(hsluv2rgb((265.874, 100., 32.301)), (0, 0, 255));
+        assert_eq!(hsluv2rgb((0., 0., 100.)), (255, 255, 255));
+        for rgb in [(119, 96, 191), (12, 200, 34), (250, 250, 5)] {
+            assert_eq!(hsluv2rgb(rgb2hsluv(rgb)), rgb);
+        }
+    }
+
+    #[test]
+    fn test_hpluv() {
+        let (h, s, l) = rgb2hpluv((255, 0, 0));
+        assert!((h - 12.177).abs() < 0.01);
+        assert!(s > 100.);
+        assert!((l - 53.237).abs() < 0.01);
+
+        let (_, s, _) = rgb2hpluv((128, 128, 128));
+        assert!(s < 0.01);
+
+        for rgb in [(119, 96, 191), (12, 200, 34), (250, 250, 5)] {
+            assert_eq!(hpluv2rgb(rgb2hpluv(rgb)), rgb);
+        }
+    }
+}
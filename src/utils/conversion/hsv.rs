@@ -1,3 +1,6 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
 /// [RGB to HSV color conversion](https://www.rapidtables.com/convert/color/rgb-to-hsv.html)
 pub fn rgb2hsv(color: (u8, u8, u8)) -> (f64, f64, f64) {
     let (r, g, b) = color;
@@ -44,12 +47,12 @@ pub fn hsv2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
     let m = v - c;
 
     let (r, g, b) = match h {
-        h if h >= 0.0 && h < 60.0 => (c, x, 0.0),
-        h if h >= 60.0 && h < 120.0 => (x, c, 0.0),
-        h if h >= 120.0 && h < 180.0 => (0.0, c, x),
-        h if h >= 180.0 && h < 240.0 => (0.0, x, c),
-        h if h >= 240.0 && h < 300.0 => (x, 0.0, c),
-        h if h >= 300.0 && h < 360.0 => (c, 0.0, x),
+        h if (0.0..60.0).contains(&h) => (c, x, 0.0),
+        h if (60.0..120.0).contains(&h) => (x, c, 0.0),
+        h if (120.0..180.0).contains(&h) => (0.0, c, x),
+        h if (180.0..240.0).contains(&h) => (0.0, x, c),
+        h if (240.0..300.0).contains(&h) => (x, 0.0, c),
+        h if (300.0..=360.0).contains(&h) => (c, 0.0, x),
         _ => panic!(),
     };
 
@@ -77,9 +80,9 @@ mod tests {
         let (h, s, v) = rgb2hsv((128, 128, 128));
         assert_eq!(h, 0.0);
         assert_eq!(s, 0.0);
-        assert_eq!(v - 0.5 < 0.1, true);
+        assert!(v - 0.5 < 0.1);
         let (h, s, v) = rgb2hsv((255, 127, 0));
-        assert_eq!(h - 30.0 < 0.1, true);
+        assert!(h - 30.0 < 0.1);
         assert_eq!(s, 1.0);
         assert_eq!(v, 1.0);
     }
@@ -93,6 +96,7 @@ mod tests {
         assert_eq!(hsv2rgb((0.0, 0.0, 0.0)), (0, 0, 0));
         assert_eq!(hsv2rgb((60.0, 1.0, 1.0)), (255, 255, 0));
         assert_eq!(hsv2rgb((300.0, 1.0, 1.0)), (255, 0, 255));
+        assert_eq!(hsv2rgb((360.0, 1.0, 1.0)), (255, 0, 0));
         assert_eq!(hsv2rgb((180.0, 1.0, 1.0)), (0, 255, 255));
         let (r, g, b) = hsv2rgb((0.0, 0.0, 0.5));
         assert_eq!(r, 128);
@@ -0,0 +1,53 @@
+use super::hsv::{hsv2rgb, rgb2hsv};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+/// [RGB to HWB color conversion](https://www.w3.org/TR/css-color-4/#rgb-to-hwb)
+///
+/// The whiteness and blackness are in the range [0, 1].
+pub fn rgb2hwb(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let (h, s, v) = rgb2hsv(color);
+    (h, (1. - s) * v, 1. - v)
+}
+
+/// [HWB to RGB color conversion](https://www.w3.org/TR/css-color-4/#hwb-to-rgb)
+///
+/// If the whiteness and blackness add up to 1 or more, the color is a gray.
+pub fn hwb2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (h, w, b) = color;
+    if w + b >= 1. {
+        let gray = (w / (w + b) * 255.).round() as u8;
+        return (gray, gray, gray);
+    }
+    let v = 1. - b;
+    let s = 1. - w / v;
+    hsv2rgb((h.rem_euclid(360.), s, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb2hwb() {
+        assert_eq!(rgb2hwb((255, 0, 0)), (0., 0., 0.));
+        assert_eq!(rgb2hwb((255, 255, 255)), (0., 1., 0.));
+        assert_eq!(rgb2hwb((0, 0, 0)), (0., 0., 1.));
+        let (h, w, b) = rgb2hwb((153, 204, 102));
+        assert_eq!(h, 90.);
+        assert!((w - 0.4).abs() < 0.001);
+        assert!((b - 0.2).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_hwb2rgb() {
+        assert_eq!(hwb2rgb((0., 0., 0.)), (255, 0, 0));
+        assert_eq!(hwb2rgb((120., 0., 0.)), (0, 255, 0));
+        assert_eq!(hwb2rgb((90., 0.4, 0.2)), (153, 204, 102));
+        assert_eq!(hwb2rgb((360., 0., 0.)), (255, 0, 0));
+        // rem_euclid rounds tiny negative hues up to 360
+        assert_eq!(hwb2rgb((-1e-15, 0., 0.)), (255, 0, 0));
+        assert_eq!(hwb2rgb((0., 0.6, 0.6)), (128, 128, 128));
+        assert_eq!(hwb2rgb((0., 1., 0.)), (255, 255, 255));
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+/// Convert a Lab-like color (CIE-L*ab, OKLab, ...) to its polar form LCh.
+///
+/// The hue is in degrees `0..360`, achromatic colors have a hue of 0.
+pub fn lab2lch(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, a, b) = color;
+    let c = (a * a + b * b).sqrt();
+    let mut h = b.atan2(a).to_degrees();
+    if h < 0. {
+        h += 360.;
+    }
+    (l, c, h)
+}
+
+/// Convert the polar form LCh back to its Lab-like color.
+pub fn lch2lab(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, c, h) = color;
+    let h = h.to_radians();
+    (l, c * h.cos(), c * h.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lab2lch() {
+        assert_eq!(lab2lch((50., 0., 0.)), (50., 0., 0.));
+        assert_eq!(lab2lch((50., 0., 10.)), (50., 10., 90.));
+        assert_eq!(lab2lch((50., 0., -10.)), (50., 10., 270.));
+    }
+
+    #[test]
+    fn test_lch2lab() {
+        let (l, a, b) = lch2lab((50., 10., 270.));
+        assert_eq!(l, 50.);
+        assert!(a.abs() < 1e-9);
+        assert!((b + 10.).abs() < 1e-9);
+    }
+}
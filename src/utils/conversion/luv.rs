@@ -0,0 +1,99 @@
+use super::white_point::WhitePoint;
+use super::xyz::{rgb2xyz, xyz2rgb};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+pub(crate) static KAPPA: f64 = 24389. / 27.;
+static EPSILON: f64 = 216. / 24389.;
+
+/// Convert RGB to CIE-L*uv
+///
+/// <https://en.wikipedia.org/wiki/CIELUV>
+pub fn rgb2luv(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    xyz2luv(rgb2xyz(color))
+}
+
+/// Convert CIE-L*uv to RGB, clipping values outside of the gamut.
+pub fn luv2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    xyz2rgb(luv2xyz(color))
+}
+
+/// Convert CIE XYZ to CIE-L*uv, relative to the D65 white
+pub fn xyz2luv(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (x, y, z) = color;
+    let (un, vn) = white_uv();
+
+    let l = if y <= EPSILON {
+        y * KAPPA
+    } else {
+        116. * y.cbrt() - 16.
+    };
+    let divider = x + 15. * y + 3. * z;
+    if l == 0. || divider == 0. {
+        return (0., 0., 0.);
+    }
+    let u = 4. * x / divider;
+    let v = 9. * y / divider;
+
+    (l, 13. * l * (u - un), 13. * l * (v - vn))
+}
+
+/// Convert CIE-L*uv relative to the D65 white to CIE XYZ
+pub fn luv2xyz(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, u, v) = color;
+    if l == 0. {
+        return (0., 0., 0.);
+    }
+    let (un, vn) = white_uv();
+
+    let u = u / (13. * l) + un;
+    let v = v / (13. * l) + vn;
+    let y = if l <= 8. {
+        l / KAPPA
+    } else {
+        ((l + 16.) / 116.).powi(3)
+    };
+    let x = y * 9. * u / (4. * v);
+    let z = y * (12. - 3. * u - 20. * v) / (4. * v);
+
+    (x, y, z)
+}
+
+/// The chromaticity u', v' of the D65 white
+pub(crate) fn white_uv() -> (f64, f64) {
+    let (x, y, z) = WhitePoint::D65.xyz();
+    let divider = x + 15. * y + 3. * z;
+    (4. * x / divider, 9. * y / divider)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.01
+    }
+
+    #[test]
+    fn test_rgb2luv() {
+        let (l, u, v) = rgb2luv((255, 0, 0));
+        assert!(approx_equal(l, 53.24));
+        assert!(approx_equal(u, 175.01));
+        assert!(approx_equal(v, 37.76));
+
+        let (l, u, v) = rgb2luv((255, 255, 255));
+        assert!(approx_equal(l, 100.));
+        assert!(approx_equal(u, 0.));
+        assert!(approx_equal(v, 0.));
+
+        assert_eq!(rgb2luv((0, 0, 0)), (0., 0., 0.));
+    }
+
+    #[test]
+    fn test_luv2rgb() {
+        assert_eq!(luv2rgb((53.24, 175.01, 37.76)), (255, 0, 0));
+        assert_eq!(luv2rgb((0., 0., 0.)), (0, 0, 0));
+        assert_eq!(luv2rgb(rgb2luv((119, 96, 191))), (119, 96, 191));
+        assert_eq!(luv2rgb(rgb2luv((1, 2, 1))), (1, 2, 1));
+    }
+}
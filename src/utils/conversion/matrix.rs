@@ -0,0 +1,68 @@
+/// A row-major 3x3 matrix.
+pub(crate) type Matrix3 = [[f64; 3]; 3];
+
+/// Multiply a matrix by a column vector.
+pub(crate) fn transform(m: &Matrix3, v: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+        m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+        m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+    )
+}
+
+pub(crate) fn multiply(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut m = [[0.; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+pub(crate) fn invert(m: &Matrix3) -> Matrix3 {
+    let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    let inv_det = 1. / det;
+    [
+        [
+            (m[1][1] * m[2][2] - m[1][2] * m[2][1]) * inv_det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+        ],
+        [
+            (m[1][2] * m[2][0] - m[1][0] * m[2][2]) * inv_det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+        ],
+        [
+            (m[1][0] * m[2][1] - m[1][1] * m[2][0]) * inv_det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+        ],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invert() {
+        let m = [[2., 0., 1.], [1., 3., 0.], [0., 1., 4.]];
+        let identity = multiply(&m, &invert(&m));
+        for (i, row) in identity.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((cell - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform() {
+        let m = [[1., 2., 3.], [0., 1., 0.], [0., 0., 2.]];
+        assert_eq!(transform(&m, (1., 1., 1.)), (6., 1., 2.));
+    }
+}
@@ -1,7 +1,25 @@
+pub mod adaptation;
+pub mod cam16;
 pub mod cmyk;
+pub mod gamut;
+pub mod hcg;
+pub mod hct;
 pub mod hex;
+pub mod hsi;
 pub mod hsl;
+pub mod hsluv;
 pub mod hsv;
+pub mod hwb;
 pub mod lab;
+pub mod lch;
+pub mod luv;
+pub(crate) mod matrix;
 pub mod num;
+pub mod oklab;
+pub mod rgb_space;
 pub mod temperature;
+pub mod white_point;
+pub mod xyz;
+pub mod ycbcr;
+pub mod yiq;
+pub mod yuv;
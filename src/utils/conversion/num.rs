@@ -10,8 +10,7 @@ pub fn num2rgb(num: u32) -> (u8, u8, u8) {
 
 pub fn rgb2num(color: (u8, u8, u8)) -> u32 {
     let (r, g, b) = color;
-    let num = (r as u32) << 16 | (g as u32) << 8 | b as u32;
-    num
+    (r as u32) << 16 | (g as u32) << 8 | b as u32
 }
 
 #[cfg(test)]
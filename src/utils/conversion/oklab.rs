@@ -0,0 +1,72 @@
+use super::matrix::{self, Matrix3};
+use super::xyz::rgb2xyz;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+static XYZ_TO_LMS: Matrix3 = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+static LMS_TO_OKLAB: Matrix3 = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+
+/// Convert RGB to OKLab
+///
+/// <https://bottosson.github.io/posts/oklab/>
+pub fn rgb2oklab(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    xyz2oklab(rgb2xyz(color))
+}
+
+/// Convert CIE XYZ (D65) to OKLab
+pub fn xyz2oklab(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::transform(&XYZ_TO_LMS, color);
+    matrix::transform(&LMS_TO_OKLAB, (l.cbrt(), m.cbrt(), s.cbrt()))
+}
+
+/// Convert OKLab to CIE XYZ (D65)
+pub fn oklab2xyz(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::transform(&matrix::invert(&LMS_TO_OKLAB), color);
+    matrix::transform(
+        &matrix::invert(&XYZ_TO_LMS),
+        (l * l * l, m * m * m, s * s * s),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.001
+    }
+
+    #[test]
+    fn test_rgb2oklab() {
+        let (l, a, b) = rgb2oklab((255, 255, 255));
+        assert!(approx_equal(l, 1.));
+        assert!(approx_equal(a, 0.));
+        assert!(approx_equal(b, 0.));
+
+        let (l, a, b) = rgb2oklab((255, 0, 0));
+        assert!(approx_equal(l, 0.628));
+        assert!(approx_equal(a, 0.2249));
+        assert!(approx_equal(b, 0.1258));
+    }
+
+    #[test]
+    fn test_oklab2xyz() {
+        let (x, y, z) = oklab2xyz((1., 0., 0.));
+        assert!(approx_equal(x, 0.9505));
+        assert!(approx_equal(y, 1.));
+        assert!(approx_equal(z, 1.089));
+
+        let (x, y, z) = oklab2xyz(xyz2oklab((0.2, 0.3, 0.4)));
+        assert!(approx_equal(x, 0.2));
+        assert!(approx_equal(y, 0.3));
+        assert!(approx_equal(z, 0.4));
+    }
+}
@@ -0,0 +1,313 @@
+use super::adaptation::Adaptation;
+use super::matrix::{self, Matrix3};
+use super::white_point::WhitePoint;
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+// ACES white point, approximately D60
+const ACES_WHITE: WhitePoint = WhitePoint::Custom {
+    x: 0.32168,
+    y: 0.33767,
+};
+
+// Rec. 2020 transfer function constants
+static REC2020_ALPHA: f64 = 1.09929682680944;
+static REC2020_BETA: f64 = 0.018053968510807;
+
+/// Transfer function ("gamma") between encoded and linear-light channel values.
+///
+/// All functions are extended to negative values by mirroring, as in CSS Color 4.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TransferFunction {
+    /// No encoding, the channels are linear light
+    Linear,
+    /// Pure power function, e.g. 563/256 for Adobe RGB
+    Gamma(f64),
+    /// The sRGB piecewise curve, also used by Display P3
+    Srgb,
+    /// ITU-R BT.2020
+    Rec2020,
+    /// ROMM RGB
+    ProPhoto,
+}
+
+impl TransferFunction {
+    /// Decode a channel in `0..1` to linear light.
+    pub fn to_linear(&self, v: f64) -> f64 {
+        let sign = v.signum();
+        let abs = v.abs();
+        let linear = match *self {
+            TransferFunction::Linear => abs,
+            TransferFunction::Gamma(gamma) => abs.powf(gamma),
+            TransferFunction::Srgb => {
+                if abs <= 0.04045 {
+                    abs / 12.92
+                } else {
+                    ((abs + 0.055) / 1.055).powf(2.4)
+                }
+            }
+            TransferFunction::Rec2020 => {
+                if abs < REC2020_BETA * 4.5 {
+                    abs / 4.5
+                } else {
+                    ((abs + REC2020_ALPHA - 1.) / REC2020_ALPHA).powf(1. / 0.45)
+                }
+            }
+            TransferFunction::ProPhoto => {
+                if abs <= 16. / 512. {
+                    abs / 16.
+                } else {
+                    abs.powf(1.8)
+                }
+            }
+        };
+        sign * linear
+    }
+
+    /// Encode a linear light channel to `0..1`.
+    pub fn from_linear(&self, v: f64) -> f64 {
+        let sign = v.signum();
+        let abs = v.abs();
+        let encoded = match *self {
+            TransferFunction::Linear => abs,
+            TransferFunction::Gamma(gamma) => abs.powf(1. / gamma),
+            TransferFunction::Srgb => {
+                if abs <= 0.0031308 {
+                    abs * 12.92
+                } else {
+                    1.055 * abs.powf(1. / 2.4) - 0.055
+                }
+            }
+            TransferFunction::Rec2020 => {
+                if abs < REC2020_BETA {
+                    abs * 4.5
+                } else {
+                    REC2020_ALPHA * abs.powf(0.45) - (REC2020_ALPHA - 1.)
+                }
+            }
+            TransferFunction::ProPhoto => {
+                if abs < 1. / 512. {
+                    abs * 16.
+                } else {
+                    abs.powf(1. / 1.8)
+                }
+            }
+        };
+        sign * encoded
+    }
+}
+
+/// An RGB color space, defined by its primaries, white point and transfer function.
+///
+/// Channels of an RGB space are in the range `0..1`, values outside of it are out of gamut.
+///
+/// ```
+/// use chroma_rust::RgbSpace;
+///
+/// let (r, g, b) = RgbSpace::SRGB.convert((1., 0., 0.), &RgbSpace::DISPLAY_P3);
+/// assert!((r - 0.9175).abs() < 0.001);
+/// assert!((g - 0.2003).abs() < 0.001);
+/// assert!((b - 0.1386).abs() < 0.001);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RgbSpace {
+    /// Identifier, as used by the CSS `color()` function
+    pub name: &'static str,
+    /// Chromaticity coordinates of the red, green and blue primaries
+    pub primaries: [(f64, f64); 3],
+    pub white: WhitePoint,
+    pub transfer: TransferFunction,
+}
+
+impl RgbSpace {
+    pub const SRGB: RgbSpace = RgbSpace {
+        name: "srgb",
+        primaries: [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Srgb,
+    };
+
+    pub const SRGB_LINEAR: RgbSpace = RgbSpace {
+        name: "srgb-linear",
+        primaries: [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Linear,
+    };
+
+    pub const DISPLAY_P3: RgbSpace = RgbSpace {
+        name: "display-p3",
+        primaries: [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Srgb,
+    };
+
+    pub const REC2020: RgbSpace = RgbSpace {
+        name: "rec2020",
+        primaries: [(0.708, 0.292), (0.170, 0.797), (0.131, 0.046)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Rec2020,
+    };
+
+    pub const ADOBE_RGB: RgbSpace = RgbSpace {
+        name: "a98-rgb",
+        primaries: [(0.64, 0.33), (0.21, 0.71), (0.15, 0.06)],
+        white: WhitePoint::D65,
+        transfer: TransferFunction::Gamma(563. / 256.),
+    };
+
+    pub const PROPHOTO: RgbSpace = RgbSpace {
+        name: "prophoto-rgb",
+        primaries: [
+            (0.734699, 0.265301),
+            (0.159597, 0.840403),
+            (0.036598, 0.000105),
+        ],
+        white: WhitePoint::D50,
+        transfer: TransferFunction::ProPhoto,
+    };
+
+    pub const ACESCG: RgbSpace = RgbSpace {
+        name: "acescg",
+        primaries: [(0.713, 0.293), (0.165, 0.830), (0.128, 0.044)],
+        white: ACES_WHITE,
+        transfer: TransferFunction::Linear,
+    };
+
+    /// Find a built-in space by its name.
+    pub fn from_name(name: &str) -> Option<RgbSpace> {
+        [
+            RgbSpace::SRGB,
+            RgbSpace::SRGB_LINEAR,
+            RgbSpace::DISPLAY_P3,
+            RgbSpace::REC2020,
+            RgbSpace::ADOBE_RGB,
+            RgbSpace::PROPHOTO,
+            RgbSpace::ACESCG,
+        ]
+        .into_iter()
+        .find(|space| space.name == name)
+    }
+
+    /// The matrix from linear RGB to XYZ, relative to the white of the space.
+    ///
+    /// <http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html>
+    pub(crate) fn xyz_matrix(&self) -> Matrix3 {
+        let columns = self.primaries.map(|(x, y)| [x / y, 1., (1. - x - y) / y]);
+        let p = [
+            [columns[0][0], columns[1][0], columns[2][0]],
+            [columns[0][1], columns[1][1], columns[2][1]],
+            [columns[0][2], columns[1][2], columns[2][2]],
+        ];
+        let (sr, sg, sb) = matrix::transform(&matrix::invert(&p), self.white.xyz());
+        let scale = [[sr, 0., 0.], [0., sg, 0.], [0., 0., sb]];
+        matrix::multiply(&p, &scale)
+    }
+
+    /// Decode the channels to linear light.
+    pub fn to_linear(&self, rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+        let t = self.transfer;
+        (t.to_linear(rgb.0), t.to_linear(rgb.1), t.to_linear(rgb.2))
+    }
+
+    /// Encode linear light channels.
+    pub fn from_linear(&self, rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+        let t = self.transfer;
+        (
+            t.from_linear(rgb.0),
+            t.from_linear(rgb.1),
+            t.from_linear(rgb.2),
+        )
+    }
+
+    /// Convert to CIE XYZ, relative to the D65 white like [`crate::Color::xyz`].
+    pub fn to_xyz(&self, rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+        let xyz = matrix::transform(&self.xyz_matrix(), self.to_linear(rgb));
+        Adaptation::Bradford.adapt(xyz, self.white, WhitePoint::D65)
+    }
+
+    /// Convert from CIE XYZ relative to the D65 white.
+    pub fn from_xyz(&self, xyz: (f64, f64, f64)) -> (f64, f64, f64) {
+        let xyz = Adaptation::Bradford.adapt(xyz, WhitePoint::D65, self.white);
+        self.from_linear(matrix::transform(&matrix::invert(&self.xyz_matrix()), xyz))
+    }
+
+    /// Convert channels of this space to the channels of another one.
+    pub fn convert(&self, rgb: (f64, f64, f64), to: &RgbSpace) -> (f64, f64, f64) {
+        if self == to {
+            return rgb;
+        }
+        to.from_xyz(self.to_xyz(rgb))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 0.001 && (a.1 - b.1).abs() < 0.001 && (a.2 - b.2).abs() < 0.001
+    }
+
+    #[test]
+    fn test_transfer_round_trip() {
+        for transfer in [
+            TransferFunction::Linear,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Srgb,
+            TransferFunction::Rec2020,
+            TransferFunction::ProPhoto,
+        ] {
+            for v in [-0.5, 0., 0.001, 0.02, 0.3, 1., 1.2] {
+                assert!((transfer.from_linear(transfer.to_linear(v)) - v).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_xyz_matrix() {
+        let m = RgbSpace::SRGB.xyz_matrix();
+        assert!((m[0][0] - 0.4124564).abs() < 0.0001);
+        assert!((m[1][1] - 0.7151522).abs() < 0.0001);
+        assert!((m[2][2] - 0.9503041).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_convert() {
+        let red = (1., 0., 0.);
+        let srgb = RgbSpace::SRGB;
+        assert!(approx_equal(
+            srgb.convert(red, &RgbSpace::DISPLAY_P3),
+            (0.9175, 0.2003, 0.1386)
+        ));
+        assert!(approx_equal(
+            srgb.convert(red, &RgbSpace::REC2020),
+            (0.7920, 0.2310, 0.0738)
+        ));
+        assert!(approx_equal(
+            srgb.convert(red, &RgbSpace::ADOBE_RGB),
+            (0.8587, 0., 0.)
+        ));
+        assert!(approx_equal(
+            srgb.convert(red, &RgbSpace::PROPHOTO),
+            (0.7022, 0.2757, 0.1036)
+        ));
+        assert!(approx_equal(
+            srgb.convert(red, &RgbSpace::SRGB_LINEAR),
+            (1., 0., 0.)
+        ));
+
+        let white = (1., 1., 1.);
+        for space in [RgbSpace::ACESCG, RgbSpace::PROPHOTO, RgbSpace::REC2020] {
+            assert!(approx_equal(srgb.convert(white, &space), white));
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            RgbSpace::from_name("display-p3"),
+            Some(RgbSpace::DISPLAY_P3)
+        );
+        assert_eq!(RgbSpace::from_name("p3"), None);
+    }
+}
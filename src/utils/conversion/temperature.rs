@@ -1,3 +1,6 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
 /// Convert an rgb in JSON format into to a Kelvin color temperature
 /// more see [color-temperature](https://github.com/neilbartlett/color-temperature)
 pub fn rgb2temperature(color: (u8, u8, u8)) -> f64 {
@@ -29,18 +32,18 @@ pub fn _color_temperature2rgb_using_th(kelvin: f64) -> (u8, u8, u8) {
     } else {
         r = temp - 60.0;
         r = 329.698727446 * r.powf(-0.1332047592);
-        r = r.max(0.0).min(255.0);
+        r = r.clamp(0.0, 255.0);
     }
 
     let mut g: f64;
     if temp < 66.0 {
         g = temp;
         g = 99.4708025861 * g.ln() - 161.1195681661;
-        g = g.max(0.0).min(255.0);
+        g = g.clamp(0.0, 255.0);
     } else {
         g = temp - 60.0;
         g = 288.1221695283 * g.powf(-0.0755148492);
-        g = g.max(0.0).min(255.0);
+        g = g.clamp(0.0, 255.0);
     }
 
     let mut b: f64;
@@ -52,7 +55,7 @@ pub fn _color_temperature2rgb_using_th(kelvin: f64) -> (u8, u8, u8) {
         } else {
             b = temp - 10.0;
             b = 138.5177312231 * b.ln() - 305.0447927307;
-            b = b.max(0.0).min(255.0);
+            b = b.clamp(0.0, 255.0);
         }
     }
     (r.round() as u8, g.round() as u8, b.round() as u8)
@@ -70,18 +73,18 @@ pub fn temperature2rgb(kelvin: f64) -> (u8, u8, u8) {
         // a + bx + c log(x)
         r = temp - 55.0;
         r = 351.97690566805693 + 0.114206453784165 * r - 40.25366309332127 * r.ln();
-        r = r.max(0.0).min(255.0);
+        r = r.clamp(0.0, 255.0);
     }
 
     let mut g: f64;
     if temp < 66.0 {
         g = temp - 2.0;
         g = -155.25485562709179 - 0.44596950469579133 * g + 104.49216199393888 * g.ln();
-        g = g.max(0.0).min(255.0);
+        g = g.clamp(0.0, 255.0);
     } else {
         g = temp - 50.0;
         g = 325.4494125711974 + 0.07943456536662342 * g - 28.0852963507957 * g.ln();
-        g = g.max(0.0).min(255.0);
+        g = g.clamp(0.0, 255.0);
     }
 
     let mut b: f64;
@@ -93,7 +96,7 @@ pub fn temperature2rgb(kelvin: f64) -> (u8, u8, u8) {
         } else {
             b = temp - 10.0;
             b = -254.76935184120902 + 0.8274096064007395 * b + 115.67994401066147 * b.ln();
-            b = b.max(0.0).min(255.0);
+            b = b.clamp(0.0, 255.0);
         }
     }
 
@@ -103,6 +106,7 @@ pub fn temperature2rgb(kelvin: f64) -> (u8, u8, u8) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
     fn test_temperature2rgb() {
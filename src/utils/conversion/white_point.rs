@@ -0,0 +1,96 @@
+/// Reference white of a color space, for the CIE 1931 2° standard observer.
+///
+/// <https://en.wikipedia.org/wiki/Standard_illuminant#White_points_of_standard_illuminants>
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum WhitePoint {
+    /// Incandescent / tungsten
+    A,
+    /// Average / North sky daylight
+    C,
+    /// Horizon light, used by ICC profiles and print
+    D50,
+    /// Mid-morning / mid-afternoon daylight
+    D55,
+    /// Noon daylight, the white of sRGB
+    D65,
+    /// North sky daylight
+    D75,
+    /// Equal energy
+    E,
+    /// Cool white fluorescent
+    F2,
+    /// D65 simulator, daylight simulator
+    F7,
+    /// Philips TL84, Ultralume 40
+    F11,
+    /// A white given by its chromaticity coordinates
+    Custom { x: f64, y: f64 },
+}
+
+impl WhitePoint {
+    /// Tristimulus values normalized to `y == 1`.
+    ///
+    /// ```
+    /// use chroma_rust::WhitePoint;
+    ///
+    /// assert_eq!(WhitePoint::D65.xyz(), (0.95047, 1., 1.08883));
+    /// ```
+    pub fn xyz(&self) -> (f64, f64, f64) {
+        match *self {
+            WhitePoint::A => (1.09850, 1., 0.35585),
+            WhitePoint::C => (0.98074, 1., 1.18232),
+            WhitePoint::D50 => (0.96422, 1., 0.82521),
+            WhitePoint::D55 => (0.95682, 1., 0.92149),
+            WhitePoint::D65 => (0.95047, 1., 1.08883),
+            WhitePoint::D75 => (0.94972, 1., 1.22638),
+            WhitePoint::E => (1., 1., 1.),
+            WhitePoint::F2 => (0.99187, 1., 0.67395),
+            WhitePoint::F7 => (0.95044, 1., 1.08755),
+            WhitePoint::F11 => (1.00966, 1., 0.64370),
+            WhitePoint::Custom { x, y } => (x / y, 1., (1. - x - y) / y),
+        }
+    }
+
+    /// Chromaticity coordinates `x` and `y`.
+    pub fn xy(&self) -> (f64, f64) {
+        match *self {
+            WhitePoint::Custom { x, y } => (x, y),
+            _ => {
+                let (x, y, z) = self.xyz();
+                let sum = x + y + z;
+                (x / sum, y / sum)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xyz() {
+        assert_eq!(WhitePoint::D65.xyz(), (0.95047, 1., 1.08883));
+        assert_eq!(WhitePoint::E.xyz(), (1., 1., 1.));
+
+        let (x, y, z) = WhitePoint::Custom {
+            x: 0.34567,
+            y: 0.35850,
+        }
+        .xyz();
+        assert!((x - 0.96422).abs() < 0.0001);
+        assert_eq!(y, 1.);
+        assert!((z - 0.82521).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_xy() {
+        let (x, y) = WhitePoint::D65.xy();
+        assert!((x - 0.3127).abs() < 0.0001);
+        assert!((y - 0.3290).abs() < 0.0001);
+
+        let (x, y) = WhitePoint::D50.xy();
+        assert!((x - 0.3457).abs() < 0.0001);
+        assert!((y - 0.3585).abs() < 0.0001);
+    }
+}
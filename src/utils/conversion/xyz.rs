@@ -0,0 +1,178 @@
+use super::matrix::{self, Matrix3};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+// D65 chromaticity, used as xyY of black where x and y are undefined
+static D65_X: f64 = 0.31270;
+static D65_Y: f64 = 0.32900;
+
+/// Linear sRGB to CIE XYZ (D65)
+pub(crate) static RGB_XYZ: Matrix3 = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.0721750],
+    [0.0193339, 0.1191920, 0.9503041],
+];
+/// CIE XYZ (D65) to linear sRGB
+pub(crate) static XYZ_RGB: Matrix3 = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.9692660, 1.8760108, 0.0415560],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+/// CIE 1931 XYZ tristimulus values.
+///
+/// The values are relative, so the D65 white of sRGB has `y == 1`.
+///
+/// ```
+/// use chroma_rust::{Color, Xyz};
+///
+/// let color = Color::from(Xyz::new(0.9505, 1.0, 1.089));
+/// assert_eq!(color.hex(), "#ffffff");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Xyz {
+    pub fn new(x: f64, y: f64, z: f64) -> Xyz {
+        Xyz { x, y, z }
+    }
+
+    /// Construct from CIE xyY, i.e. chromaticity `x`, `y` and luminance `big_y`.
+    pub fn from_xyy(x: f64, y: f64, big_y: f64) -> Xyz {
+        let (x, y, z) = xyy2xyz((x, y, big_y));
+        Xyz { x, y, z }
+    }
+
+    /// Return the CIE xyY representation.
+    pub fn xyy(&self) -> (f64, f64, f64) {
+        xyz2xyy((self.x, self.y, self.z))
+    }
+}
+
+impl From<(f64, f64, f64)> for Xyz {
+    fn from(xyz: (f64, f64, f64)) -> Self {
+        Xyz::new(xyz.0, xyz.1, xyz.2)
+    }
+}
+
+impl From<Xyz> for (f64, f64, f64) {
+    fn from(xyz: Xyz) -> Self {
+        (xyz.x, xyz.y, xyz.z)
+    }
+}
+
+/// Convert sRGB to CIE XYZ (D65)
+///
+/// <https://en.wikipedia.org/wiki/SRGB#From_sRGB_to_CIE_XYZ>
+pub fn rgb2xyz(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let rgb = (
+        rgb_xyz(color.0 as f64),
+        rgb_xyz(color.1 as f64),
+        rgb_xyz(color.2 as f64),
+    );
+    matrix::transform(&RGB_XYZ, rgb)
+}
+
+/// Convert CIE XYZ (D65) to sRGB, clipping values outside of the gamut.
+pub fn xyz2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (r, g, b) = matrix::transform(&XYZ_RGB, color);
+    (
+        xyz_rgb(r).round() as u8,
+        xyz_rgb(g).round() as u8,
+        xyz_rgb(b).round() as u8,
+    )
+}
+
+/// Convert CIE XYZ to CIE xyY
+///
+/// <https://en.wikipedia.org/wiki/CIE_1931_color_space#CIE_xy_chromaticity_diagram_and_the_CIE_xyY_color_space>
+pub fn xyz2xyy(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (x, y, z) = color;
+    let sum = x + y + z;
+    if sum == 0. {
+        return (D65_X, D65_Y, 0.);
+    }
+    (x / sum, y / sum, y)
+}
+
+/// Convert CIE xyY to CIE XYZ
+pub fn xyy2xyz(color: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (x, y, big_y) = color;
+    if y == 0. {
+        return (0., 0., 0.);
+    }
+    (x * big_y / y, big_y, (1. - x - y) * big_y / y)
+}
+
+/// sRGB companding, from a channel in `0..255` to linear light in `0..1`
+pub(crate) fn rgb_xyz(mut r: f64) -> f64 {
+    r /= 255.0;
+    if r <= 0.04045 {
+        r / 12.92
+    } else {
+        ((r + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Inverse sRGB companding, from linear light in `0..1` to a channel in `0..255`
+pub(crate) fn xyz_rgb(r: f64) -> f64 {
+    255. * if r <= 0.00304 {
+        12.92 * r
+    } else {
+        1.055 * r.powf(1. / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.001
+    }
+
+    #[test]
+    fn test_rgb2xyz() {
+        let (x, y, z) = rgb2xyz((255, 255, 255));
+        assert!(approx_equal(x, 0.9505));
+        assert!(approx_equal(y, 1.));
+        assert!(approx_equal(z, 1.089));
+
+        let (x, y, z) = rgb2xyz((255, 0, 0));
+        assert!(approx_equal(x, 0.4125));
+        assert!(approx_equal(y, 0.2127));
+        assert!(approx_equal(z, 0.0193));
+    }
+
+    #[test]
+    fn test_xyz2rgb() {
+        assert_eq!(xyz2rgb((0.9505, 1., 1.089)), (255, 255, 255));
+        assert_eq!(xyz2rgb((0.4125, 0.2127, 0.0193)), (255, 0, 0));
+        assert_eq!(xyz2rgb((0., 0., 0.)), (0, 0, 0));
+        assert_eq!(xyz2rgb(rgb2xyz((119, 96, 191))), (119, 96, 191));
+    }
+
+    #[test]
+    fn test_xyz2xyy() {
+        let (x, y, big_y) = xyz2xyy(rgb2xyz((255, 255, 255)));
+        assert!(approx_equal(x, 0.3127));
+        assert!(approx_equal(y, 0.3290));
+        assert!(approx_equal(big_y, 1.));
+
+        assert_eq!(xyz2xyy((0., 0., 0.)), (0.31270, 0.32900, 0.));
+    }
+
+    #[test]
+    fn test_xyy2xyz() {
+        let (x, y, z) = xyy2xyz((0.3127, 0.3290, 1.));
+        assert!(approx_equal(x, 0.9505));
+        assert!(approx_equal(y, 1.));
+        assert!(approx_equal(z, 1.089));
+
+        assert_eq!(xyy2xyz((0.3127, 0., 0.)), (0., 0., 0.));
+    }
+}
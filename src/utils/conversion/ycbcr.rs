@@ -0,0 +1,182 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+/// The ITU-R recommendation defining the luma coefficients of YCbCr.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum YCbCrStandard {
+    /// BT.601, standard definition video and JPEG
+    #[default]
+    Bt601,
+    /// BT.709, high definition video
+    Bt709,
+    /// BT.2020, ultra high definition video
+    Bt2020,
+}
+
+impl YCbCrStandard {
+    /// The red and blue luma coefficients `Kr` and `Kb`.
+    fn coefficients(&self) -> (f64, f64) {
+        match self {
+            YCbCrStandard::Bt601 => (0.299, 0.114),
+            YCbCrStandard::Bt709 => (0.2126, 0.0722),
+            YCbCrStandard::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+}
+
+/// The range of the 8-bit YCbCr code values.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum YCbCrRange {
+    /// Y, Cb and Cr in `0..255`, as used by JPEG
+    #[default]
+    Full,
+    /// Y in `16..235`, Cb and Cr in `16..240`, as used by broadcast video
+    Limited,
+}
+
+impl YCbCrRange {
+    /// The offset and the scale of the luma and the chroma.
+    fn scale(&self) -> (f64, f64, f64) {
+        match self {
+            YCbCrRange::Full => (0., 255., 255.),
+            YCbCrRange::Limited => (16., 219., 224.),
+        }
+    }
+}
+
+/// Find the standard and range of a mode name, e.g. `ycbcr-bt709-limited`.
+///
+/// `ycbcr` alone is BT.601 in full range, as used by JPEG.
+pub fn ycbcr_mode(mode: &str) -> Option<(YCbCrStandard, YCbCrRange)> {
+    let rest = mode.strip_prefix("ycbcr")?;
+    let (rest, range) = match rest.strip_suffix("-limited") {
+        Some(rest) => (rest, YCbCrRange::Limited),
+        None => (rest, YCbCrRange::Full),
+    };
+    let standard = match rest {
+        "" | "-bt601" => YCbCrStandard::Bt601,
+        "-bt709" => YCbCrStandard::Bt709,
+        "-bt2020" => YCbCrStandard::Bt2020,
+        _ => return None,
+    };
+    Some((standard, range))
+}
+
+/// [RGB to YCbCr color conversion](https://en.wikipedia.org/wiki/YCbCr)
+///
+/// Returns 8-bit code values, the gamma encoded RGB channels are used as is.
+pub fn rgb2ycbcr(
+    color: (u8, u8, u8),
+    standard: YCbCrStandard,
+    range: YCbCrRange,
+) -> (f64, f64, f64) {
+    let (kr, kb) = standard.coefficients();
+    let (offset, y_scale, c_scale) = range.scale();
+    let r = color.0 as f64 / 255.;
+    let g = color.1 as f64 / 255.;
+    let b = color.2 as f64 / 255.;
+
+    let y = kr * r + (1. - kr - kb) * g + kb * b;
+    let cb = (b - y) / (2. * (1. - kb));
+    let cr = (r - y) / (2. * (1. - kr));
+
+    (
+        offset + y_scale * y,
+        128. + c_scale * cb,
+        128. + c_scale * cr,
+    )
+}
+
+/// YCbCr to RGB color conversion
+pub fn ycbcr2rgb(
+    color: (f64, f64, f64),
+    standard: YCbCrStandard,
+    range: YCbCrRange,
+) -> (u8, u8, u8) {
+    let (kr, kb) = standard.coefficients();
+    let (offset, y_scale, c_scale) = range.scale();
+    let y = (color.0 - offset) / y_scale;
+    let cb = (color.1 - 128.) / c_scale;
+    let cr = (color.2 - 128.) / c_scale;
+
+    let r = y + 2. * (1. - kr) * cr;
+    let b = y + 2. * (1. - kb) * cb;
+    let g = (y - kr * r - kb * b) / (1. - kr - kb);
+
+    (
+        (r * 255.).round() as u8,
+        (g * 255.).round() as u8,
+        (b * 255.).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_equal(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 0.01 && (a.1 - b.1).abs() < 0.01 && (a.2 - b.2).abs() < 0.01
+    }
+
+    #[test]
+    fn test_rgb2ycbcr() {
+        let (standard, range) = (YCbCrStandard::Bt601, YCbCrRange::Full);
+        assert!(approx_equal(
+            rgb2ycbcr((255, 255, 255), standard, range),
+            (255., 128., 128.)
+        ));
+        assert!(approx_equal(
+            rgb2ycbcr((255, 0, 0), standard, range),
+            (76.245, 84.972, 255.5)
+        ));
+
+        let range = YCbCrRange::Limited;
+        assert!(approx_equal(
+            rgb2ycbcr((0, 0, 0), standard, range),
+            (16., 128., 128.)
+        ));
+        assert!(approx_equal(
+            rgb2ycbcr((255, 255, 255), standard, range),
+            (235., 128., 128.)
+        ));
+
+        let (y, _, _) = rgb2ycbcr((0, 255, 0), YCbCrStandard::Bt709, YCbCrRange::Full);
+        assert!((y - 0.7152 * 255.).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ycbcr2rgb() {
+        for standard in [
+            YCbCrStandard::Bt601,
+            YCbCrStandard::Bt709,
+            YCbCrStandard::Bt2020,
+        ] {
+            for range in [YCbCrRange::Full, YCbCrRange::Limited] {
+                for rgb in [(255, 0, 0), (0, 255, 0), (119, 96, 191), (255, 255, 255)] {
+                    assert_eq!(
+                        ycbcr2rgb(rgb2ycbcr(rgb, standard, range), standard, range),
+                        rgb
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ycbcr_mode() {
+        assert_eq!(
+            ycbcr_mode("ycbcr"),
+            Some((YCbCrStandard::Bt601, YCbCrRange::Full))
+        );
+        assert_eq!(
+            ycbcr_mode("ycbcr-bt709-limited"),
+            Some((YCbCrStandard::Bt709, YCbCrRange::Limited))
+        );
+        assert_eq!(
+            ycbcr_mode("ycbcr-bt2020"),
+            Some((YCbCrStandard::Bt2020, YCbCrRange::Full))
+        );
+        assert_eq!(ycbcr_mode("ycbcr-bt2100"), None);
+        assert_eq!(ycbcr_mode("yuv"), None);
+    }
+}
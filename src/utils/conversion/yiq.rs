@@ -0,0 +1,53 @@
+use super::matrix::{self, Matrix3};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+static RGB_TO_YIQ: Matrix3 = [
+    [0.299, 0.587, 0.114],
+    [0.5959, -0.2746, -0.3213],
+    [0.2115, -0.5227, 0.3112],
+];
+
+/// [RGB to YIQ color conversion](https://en.wikipedia.org/wiki/YIQ), as used by NTSC
+///
+/// Y is in the range [0, 1], I in [-0.5959, 0.5959] and Q in [-0.5227, 0.5227].
+pub fn rgb2yiq(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = color;
+    let rgb = (r as f64 / 255., g as f64 / 255., b as f64 / 255.);
+    matrix::transform(&RGB_TO_YIQ, rgb)
+}
+
+/// YIQ to RGB color conversion
+pub fn yiq2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (r, g, b) = matrix::transform(&matrix::invert(&RGB_TO_YIQ), color);
+    (
+        (r * 255.).round() as u8,
+        (g * 255.).round() as u8,
+        (b * 255.).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb2yiq() {
+        let (y, i, q) = rgb2yiq((255, 255, 255));
+        assert!((y - 1.).abs() < 0.001);
+        assert!(i.abs() < 0.001);
+        assert!(q.abs() < 0.001);
+
+        let (y, i, q) = rgb2yiq((255, 0, 0));
+        assert!((y - 0.299).abs() < 0.001);
+        assert!((i - 0.5959).abs() < 0.001);
+        assert!((q - 0.2115).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_yiq2rgb() {
+        assert_eq!(yiq2rgb((1., 0., 0.)), (255, 255, 255));
+        assert_eq!(yiq2rgb((0.299, 0.5959, 0.2115)), (255, 0, 0));
+        assert_eq!(yiq2rgb(rgb2yiq((119, 96, 191))), (119, 96, 191));
+    }
+}
@@ -0,0 +1,53 @@
+use super::matrix::{self, Matrix3};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+static RGB_TO_YUV: Matrix3 = [
+    [0.299, 0.587, 0.114],
+    [-0.14713, -0.28886, 0.436],
+    [0.615, -0.51499, -0.10001],
+];
+
+/// [RGB to YUV color conversion](https://en.wikipedia.org/wiki/Y%E2%80%B2UV), analog BT.601
+///
+/// Y is in the range [0, 1], U in [-0.436, 0.436] and V in [-0.615, 0.615].
+pub fn rgb2yuv(color: (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = color;
+    let rgb = (r as f64 / 255., g as f64 / 255., b as f64 / 255.);
+    matrix::transform(&RGB_TO_YUV, rgb)
+}
+
+/// YUV to RGB color conversion
+pub fn yuv2rgb(color: (f64, f64, f64)) -> (u8, u8, u8) {
+    let (r, g, b) = matrix::transform(&matrix::invert(&RGB_TO_YUV), color);
+    (
+        (r * 255.).round() as u8,
+        (g * 255.).round() as u8,
+        (b * 255.).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb2yuv() {
+        let (y, u, v) = rgb2yuv((255, 255, 255));
+        assert!((y - 1.).abs() < 0.001);
+        assert!(u.abs() < 0.001);
+        assert!(v.abs() < 0.001);
+
+        let (y, u, v) = rgb2yuv((0, 0, 255));
+        assert!((y - 0.114).abs() < 0.001);
+        assert!((u - 0.436).abs() < 0.001);
+        assert!((v + 0.1).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_yuv2rgb() {
+        assert_eq!(yuv2rgb((1., 0., 0.)), (255, 255, 255));
+        assert_eq!(yuv2rgb((0.299, -0.14713, 0.615)), (255, 0, 0));
+        assert_eq!(yuv2rgb(rgb2yuv((119, 96, 191))), (119, 96, 191));
+    }
+}
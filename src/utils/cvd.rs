@@ -0,0 +1,604 @@
+use alloc::{vec, vec::Vec};
+
+use crate::utils::conversion::matrix::{self, Matrix3};
+use crate::utils::conversion::xyz::{rgb_xyz, xyz_rgb};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+use crate::{delta_e, Color, RgbSpace};
+
+/// A color vision deficiency.
+///
+/// The dichromacies are simulated with a severity, from normal vision at `0`
+/// to the full deficiency at `1` (e.g. protanomaly to protanopia).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CvdKind {
+    /// Missing or anomalous L (red) cones
+    Protanopia,
+    /// Missing or anomalous M (green) cones
+    Deuteranopia,
+    /// Missing or anomalous S (blue) cones
+    Tritanopia,
+    /// No color vision, only the luminance is seen
+    Achromatopsia,
+}
+
+impl CvdKind {
+    pub(crate) const DICHROMACIES: [CvdKind; 3] = [
+        CvdKind::Protanopia,
+        CvdKind::Deuteranopia,
+        CvdKind::Tritanopia,
+    ];
+}
+
+/// The model simulating the dichromacies.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum CvdModel {
+    /// Machado, Oliveira and Fernandes (2009), the most accurate for partial severities
+    ///
+    /// <https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html>
+    #[default]
+    Machado,
+    /// Brettel, Viénot and Mollon (1997), projecting onto two half-planes
+    Brettel,
+    /// Viénot, Brettel and Mollon (1999), projecting onto a single plane,
+    /// tritanopia is simulated with [`CvdModel::Brettel`]
+    Vienot,
+}
+
+/// Simulate how a color is seen with a color vision deficiency.
+pub(crate) fn simulate(
+    rgb: (u8, u8, u8),
+    kind: CvdKind,
+    severity: f64,
+    model: CvdModel,
+) -> (u8, u8, u8) {
+    let linear = (
+        rgb_xyz(rgb.0 as f64),
+        rgb_xyz(rgb.1 as f64),
+        rgb_xyz(rgb.2 as f64),
+    );
+    let (r, g, b) = simulate_linear(linear, kind, severity, model);
+    let channel = |v: f64| xyz_rgb(v.clamp(0., 1.)).round() as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+/// Simulate a color vision deficiency on linear sRGB.
+pub(crate) fn simulate_linear(
+    rgb: (f64, f64, f64),
+    kind: CvdKind,
+    severity: f64,
+    model: CvdModel,
+) -> (f64, f64, f64) {
+    let severity = severity.clamp(0., 1.);
+    if kind == CvdKind::Achromatopsia {
+        let m = RgbSpace::SRGB.xyz_matrix();
+        let y = m[1][0] * rgb.0 + m[1][1] * rgb.1 + m[1][2] * rgb.2;
+        return mix(rgb, (y, y, y), severity);
+    }
+    match model {
+        CvdModel::Machado => matrix::transform(&machado(kind, severity), rgb),
+        CvdModel::Brettel => mix(rgb, brettel(rgb, kind), severity),
+        CvdModel::Vienot if kind == CvdKind::Tritanopia => mix(rgb, brettel(rgb, kind), severity),
+        CvdModel::Vienot => mix(rgb, matrix::transform(&vienot(kind), rgb), severity),
+    }
+}
+
+fn mix(from: (f64, f64, f64), to: (f64, f64, f64), t: f64) -> (f64, f64, f64) {
+    (
+        from.0 + (to.0 - from.0) * t,
+        from.1 + (to.1 - from.1) * t,
+        from.2 + (to.2 - from.2) * t,
+    )
+}
+
+/// The Machado matrix, interpolated between the tabulated severities.
+fn machado(kind: CvdKind, severity: f64) -> Matrix3 {
+    let table = match kind {
+        CvdKind::Protanopia => &MACHADO_PROTAN,
+        CvdKind::Deuteranopia => &MACHADO_DEUTAN,
+        _ => &MACHADO_TRITAN,
+    };
+    let step = severity * 10.;
+    let i = (step.floor() as usize).min(9);
+    let t = step - i as f64;
+    let (a, b) = (table[i], table[i + 1]);
+    let mut m = [[0.; 3]; 3];
+    for row in 0..3 {
+        for col in 0..3 {
+            m[row][col] = a[row][col] + (b[row][col] - a[row][col]) * t;
+        }
+    }
+    m
+}
+
+/// The Smith & Pokorny LMS cone response of linear sRGB, from Viénot et al.
+static LMS: Matrix3 = [
+    [0.178824, 0.435161, 0.0411935],
+    [0.0345565, 0.271554, 0.0386714],
+    [0.000299566, 0.00184309, 0.0146709],
+];
+
+fn missing_cone(kind: CvdKind) -> usize {
+    match kind {
+        CvdKind::Protanopia => 0,
+        CvdKind::Deuteranopia => 1,
+        _ => 2,
+    }
+}
+
+/// Replace the missing cone response so that the color lies on the plane of the normal.
+fn project(lms: (f64, f64, f64), normal: [f64; 3], cone: usize) -> (f64, f64, f64) {
+    let mut v = [lms.0, lms.1, lms.2];
+    let others: f64 = (0..3)
+        .filter(|&i| i != cone)
+        .map(|i| normal[i] * v[i])
+        .sum();
+    v[cone] = -others / normal[cone];
+    (v[0], v[1], v[2])
+}
+
+fn cross(a: (f64, f64, f64), b: (f64, f64, f64)) -> [f64; 3] {
+    [
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    ]
+}
+
+fn dot(a: [f64; 3], b: (f64, f64, f64)) -> f64 {
+    a[0] * b.0 + a[1] * b.1 + a[2] * b.2
+}
+
+/// The Viénot matrix on linear sRGB, projecting onto the plane of white and blue.
+fn vienot(kind: CvdKind) -> Matrix3 {
+    let lms = LMS;
+    let white = matrix::transform(&lms, (1., 1., 1.));
+    let blue = matrix::transform(&lms, (0., 0., 1.));
+    let normal = cross(white, blue);
+    let cone = missing_cone(kind);
+
+    let mut projection = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+    projection[cone] = [0., 0., 0.];
+    for i in (0..3).filter(|&i| i != cone) {
+        projection[cone][i] = -normal[i] / normal[cone];
+    }
+    matrix::multiply(&matrix::invert(&lms), &matrix::multiply(&projection, &lms))
+}
+
+/// The Brettel simulation on linear sRGB, projecting onto the half-plane of
+/// white and the wavelength on the same side as the color.
+fn brettel(rgb: (f64, f64, f64), kind: CvdKind) -> (f64, f64, f64) {
+    let lms = LMS;
+    let white = matrix::transform(&lms, (1., 1., 1.));
+    let cone = missing_cone(kind);
+    let (wing1, wing2) = match kind {
+        CvdKind::Tritanopia => (XYZ_485, XYZ_660),
+        _ => (XYZ_475, XYZ_575),
+    };
+    let xyz_lms = matrix::multiply(&lms, &matrix::invert(&RgbSpace::SRGB.xyz_matrix()));
+    let to_lms = |xyz| matrix::transform(&xyz_lms, xyz);
+    let (wing1, wing2) = (to_lms(wing1), to_lms(wing2));
+
+    let mut axis = (0., 0., 0.);
+    match cone {
+        0 => axis.0 = 1.,
+        1 => axis.1 = 1.,
+        _ => axis.2 = 1.,
+    }
+    let separation = cross(white, axis);
+    let color = matrix::transform(&lms, rgb);
+    let wing = if dot(separation, color).signum() == dot(separation, wing1).signum() {
+        wing1
+    } else {
+        wing2
+    };
+    let projected = project(color, cross(white, wing), cone);
+    matrix::transform(&matrix::invert(&lms), projected)
+}
+
+/// Shift the information lost with a deficiency into the channels that are still seen.
+///
+/// The difference between the color and its simulation is redistributed, as
+/// in Fidaner et al. (2005). Achromatopsia can't be corrected, the color is kept.
+pub(crate) fn daltonize(rgb: (u8, u8, u8), kind: CvdKind, severity: f64) -> (u8, u8, u8) {
+    let shift = match kind {
+        CvdKind::Protanopia | CvdKind::Deuteranopia => [[0., 0., 0.], [0.7, 1., 0.], [0.7, 0., 1.]],
+        CvdKind::Tritanopia => [[1., 0., 0.7], [0., 1., 0.7], [0., 0., 0.]],
+        CvdKind::Achromatopsia => return rgb,
+    };
+    let linear = (
+        rgb_xyz(rgb.0 as f64),
+        rgb_xyz(rgb.1 as f64),
+        rgb_xyz(rgb.2 as f64),
+    );
+    let simulated = simulate_linear(linear, kind, severity, CvdModel::Machado);
+    let error = (
+        linear.0 - simulated.0,
+        linear.1 - simulated.1,
+        linear.2 - simulated.2,
+    );
+    let (dr, dg, db) = matrix::transform(&shift, error);
+    let channel = |v: f64| xyz_rgb(v.clamp(0., 1.)).round() as u8;
+    (
+        channel(linear.0 + dr),
+        channel(linear.1 + dg),
+        channel(linear.2 + db),
+    )
+}
+
+/// How distinguishable the colors of a palette are with one kind of vision.
+#[derive(Debug, PartialEq, Clone)]
+pub struct VisionReport {
+    /// The deficiency, or `None` for normal vision
+    pub kind: Option<CvdKind>,
+    /// The smallest CIEDE2000 difference between two colors of the palette
+    pub min_distance: f64,
+    /// The indices and difference of the pairs of colors closer than the threshold
+    pub collisions: Vec<(usize, usize, f64)>,
+}
+
+/// How distinguishable the colors of a palette are, see [`check_palette`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct PaletteReport {
+    /// The smallest difference accepted between two colors
+    pub threshold: f64,
+    /// The report for normal vision, then for each deficiency
+    pub visions: Vec<VisionReport>,
+}
+
+impl PaletteReport {
+    /// Whether no pair of colors is closer than the threshold with any vision.
+    pub fn is_safe(&self) -> bool {
+        self.visions.iter().all(|v| v.collisions.is_empty())
+    }
+
+    /// The smallest difference between two colors with any vision.
+    pub fn min_distance(&self) -> f64 {
+        self.visions
+            .iter()
+            .map(|v| v.min_distance)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// The report of a deficiency, or `None` for normal vision.
+    pub fn vision(&self, kind: Option<CvdKind>) -> Option<&VisionReport> {
+        self.visions.iter().find(|v| v.kind == kind)
+    }
+}
+
+/// Check that the colors of a palette stay distinguishable with normal vision,
+/// protanopia, deuteranopia and tritanopia.
+///
+/// Pairs of colors with a CIEDE2000 difference below the threshold are flagged.
+///
+/// ```
+/// use chroma_rust::{check_palette, Color, CvdKind};
+///
+/// let palette = [Color::from("#d62728"), Color::from("#2ca02c"), Color::from("#1f77b4")];
+/// let report = check_palette(&palette, 10.);
+/// assert!(!report.is_safe());
+/// assert!(report.vision(None).unwrap().collisions.is_empty());
+/// let deuteranopia = report.vision(Some(CvdKind::Deuteranopia)).unwrap();
+/// let (i, j, _) = deuteranopia.collisions[0];
+/// assert_eq!((i, j), (0, 1)); // red and green
+/// ```
+pub fn check_palette(colors: &[Color], threshold: f64) -> PaletteReport {
+    check_palette_with(colors, threshold, &CvdKind::DICHROMACIES)
+}
+
+/// Check that the colors of a palette stay distinguishable with normal vision
+/// and the given deficiencies, simulated at full severity.
+pub fn check_palette_with(colors: &[Color], threshold: f64, kinds: &[CvdKind]) -> PaletteReport {
+    let visions = core::iter::once(None)
+        .chain(kinds.iter().map(|&kind| Some(kind)))
+        .map(|kind| {
+            let seen: Vec<Color> = match kind {
+                None => colors.to_vec(),
+                Some(kind) => colors.iter().map(|c| c.simulate_cvd(kind, 1.)).collect(),
+            };
+            let mut min_distance = f64::INFINITY;
+            let mut collisions = vec![];
+            for i in 0..seen.len() {
+                for j in i + 1..seen.len() {
+                    let distance = delta_e(&seen[i], &seen[j]);
+                    min_distance = min_distance.min(distance);
+                    if distance < threshold {
+                        collisions.push((i, j, distance));
+                    }
+                }
+            }
+            VisionReport {
+                kind,
+                min_distance,
+                collisions,
+            }
+        })
+        .collect();
+    PaletteReport { threshold, visions }
+}
+
+// CIE 1931 2° color matching functions at the wavelengths anchoring Brettel's half-planes
+static XYZ_475: (f64, f64, f64) = (0.1421, 0.1126, 1.0419);
+static XYZ_485: (f64, f64, f64) = (0.05795, 0.1693, 0.6162);
+static XYZ_575: (f64, f64, f64) = (0.8425, 0.9154, 0.0018);
+static XYZ_660: (f64, f64, f64) = (0.1649, 0.061, 0.);
+
+static IDENTITY: Matrix3 = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+
+// Machado et al. (2009), for the severities 0, 0.1, ..., 1
+static MACHADO_PROTAN: [Matrix3; 11] = [
+    IDENTITY,
+    [
+        [0.856167, 0.182038, -0.038205],
+        [0.029342, 0.955115, 0.015544],
+        [-0.002880, -0.001563, 1.004443],
+    ],
+    [
+        [0.734766, 0.334872, -0.069637],
+        [0.051840, 0.919198, 0.028963],
+        [-0.004928, -0.004209, 1.009137],
+    ],
+    [
+        [0.630323, 0.465641, -0.095964],
+        [0.069181, 0.890046, 0.040773],
+        [-0.006308, -0.007724, 1.014032],
+    ],
+    [
+        [0.539009, 0.579343, -0.118352],
+        [0.082546, 0.866121, 0.051332],
+        [-0.007136, -0.011959, 1.019095],
+    ],
+    [
+        [0.458064, 0.679578, -0.137642],
+        [0.092785, 0.846313, 0.060902],
+        [-0.007494, -0.016807, 1.024301],
+    ],
+    [
+        [0.385450, 0.769005, -0.154455],
+        [0.100526, 0.829802, 0.069673],
+        [-0.007442, -0.022190, 1.029632],
+    ],
+    [
+        [0.319627, 0.849633, -0.169261],
+        [0.106241, 0.815969, 0.077790],
+        [-0.007025, -0.028051, 1.035076],
+    ],
+    [
+        [0.259411, 0.923008, -0.182420],
+        [0.110296, 0.804340, 0.085364],
+        [-0.006276, -0.034346, 1.040622],
+    ],
+    [
+        [0.203876, 0.990338, -0.194214],
+        [0.112975, 0.794542, 0.092483],
+        [-0.005222, -0.041043, 1.046265],
+    ],
+    [
+        [0.152286, 1.052583, -0.204868],
+        [0.114503, 0.786281, 0.099216],
+        [-0.003882, -0.048116, 1.051998],
+    ],
+];
+
+static MACHADO_DEUTAN: [Matrix3; 11] = [
+    IDENTITY,
+    [
+        [0.866435, 0.177704, -0.044139],
+        [0.049567, 0.939063, 0.011370],
+        [-0.003453, 0.007233, 0.996220],
+    ],
+    [
+        [0.760729, 0.319078, -0.079807],
+        [0.090568, 0.889315, 0.020117],
+        [-0.006027, 0.013325, 0.992702],
+    ],
+    [
+        [0.675425, 0.433850, -0.109275],
+        [0.125303, 0.847755, 0.026942],
+        [-0.007950, 0.018572, 0.989378],
+    ],
+    [
+        [0.605511, 0.528560, -0.134071],
+        [0.155318, 0.812366, 0.032316],
+        [-0.009376, 0.023176, 0.986200],
+    ],
+    [
+        [0.547494, 0.607765, -0.155259],
+        [0.181692, 0.781742, 0.036566],
+        [-0.010410, 0.027275, 0.983136],
+    ],
+    [
+        [0.498864, 0.674741, -0.173604],
+        [0.205199, 0.754872, 0.039929],
+        [-0.011131, 0.030969, 0.980162],
+    ],
+    [
+        [0.457771, 0.731899, -0.189670],
+        [0.226409, 0.731012, 0.042579],
+        [-0.011595, 0.034333, 0.977261],
+    ],
+    [
+        [0.422823, 0.781057, -0.203881],
+        [0.245752, 0.709602, 0.044646],
+        [-0.011843, 0.037423, 0.974421],
+    ],
+    [
+        [0.392952, 0.823610, -0.216562],
+        [0.263559, 0.690210, 0.046232],
+        [-0.011910, 0.040281, 0.971630],
+    ],
+    [
+        [0.367322, 0.860646, -0.227968],
+        [0.280085, 0.672501, 0.047413],
+        [-0.011820, 0.042940, 0.968881],
+    ],
+];
+
+static MACHADO_TRITAN: [Matrix3; 11] = [
+    IDENTITY,
+    [
+        [0.926670, 0.092514, -0.019184],
+        [0.021191, 0.964503, 0.014306],
+        [0.008437, 0.054813, 0.936750],
+    ],
+    [
+        [0.895720, 0.133330, -0.029050],
+        [0.029997, 0.945400, 0.024603],
+        [0.013027, 0.104707, 0.882266],
+    ],
+    [
+        [0.905871, 0.127791, -0.033662],
+        [0.026856, 0.941251, 0.031893],
+        [0.013410, 0.148296, 0.838294],
+    ],
+    [
+        [0.948035, 0.089490, -0.037526],
+        [0.014364, 0.946792, 0.038844],
+        [0.010853, 0.193991, 0.795156],
+    ],
+    [
+        [1.017277, 0.027029, -0.044306],
+        [-0.006113, 0.958479, 0.047634],
+        [0.006379, 0.248708, 0.744913],
+    ],
+    [
+        [1.104996, -0.046633, -0.058363],
+        [-0.032137, 0.971635, 0.060503],
+        [0.001336, 0.317922, 0.680742],
+    ],
+    [
+        [1.193214, -0.109812, -0.083402],
+        [-0.058496, 0.979410, 0.079086],
+        [-0.002346, 0.403492, 0.598854],
+    ],
+    [
+        [1.257728, -0.139648, -0.118081],
+        [-0.078003, 0.975409, 0.102594],
+        [-0.003316, 0.501214, 0.502102],
+    ],
+    [
+        [1.278864, -0.125333, -0.153531],
+        [-0.084748, 0.957674, 0.127074],
+        [-0.000989, 0.601151, 0.399838],
+    ],
+    [
+        [1.255528, -0.076749, -0.178779],
+        [-0.078411, 0.930809, 0.147602],
+        [0.004733, 0.691367, 0.303900],
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MODELS: [CvdModel; 3] = [CvdModel::Machado, CvdModel::Brettel, CvdModel::Vienot];
+
+    #[test]
+    fn test_machado_tables() {
+        // each row sums to 1, keeping the white
+        for table in [&MACHADO_PROTAN, &MACHADO_DEUTAN, &MACHADO_TRITAN] {
+            for m in table.iter() {
+                for row in m {
+                    assert!((row.iter().sum::<f64>() - 1.).abs() < 1e-5);
+                }
+            }
+        }
+        let half = machado(CvdKind::Protanopia, 0.55);
+        assert!((half[0][0] - (0.458064 + 0.385450) / 2.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simulate_neutral() {
+        for model in MODELS {
+            for kind in CvdKind::DICHROMACIES {
+                for gray in [0, 119, 255] {
+                    let (r, g, b) = simulate((gray, gray, gray), kind, 1., model);
+                    assert!(
+                        r.abs_diff(gray) <= 1 && g.abs_diff(gray) <= 1 && b.abs_diff(gray) <= 1
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_simulate_severity() {
+        for model in MODELS {
+            for kind in CvdKind::DICHROMACIES {
+                assert_eq!(simulate((255, 0, 0), kind, 0., model), (255, 0, 0));
+            }
+        }
+        let (r, g, b) = simulate((255, 0, 0), CvdKind::Achromatopsia, 1., CvdModel::Machado);
+        assert_eq!((r, g, b), (127, 127, 127));
+    }
+
+    #[test]
+    fn test_simulate_confusion() {
+        // red and green are confused with protanopia and deuteranopia
+        for model in MODELS {
+            for kind in [CvdKind::Protanopia, CvdKind::Deuteranopia] {
+                let (r, g, b) = simulate((255, 0, 0), kind, 1., model);
+                assert!(
+                    r.abs_diff(g) < 80,
+                    "{:?} {:?}: {:?}",
+                    model,
+                    kind,
+                    (r, g, b)
+                );
+            }
+        }
+        // the Viénot matrix of DaltonLens
+        let m = vienot(CvdKind::Protanopia);
+        assert!((m[0][0] - 0.11238).abs() < 0.005);
+        assert!((m[0][1] - 0.88762).abs() < 0.005);
+    }
+
+    #[test]
+    fn test_brettel_tritanopia() {
+        // blue and green are confused with tritanopia
+        let blue = simulate((0, 0, 255), CvdKind::Tritanopia, 1., CvdModel::Brettel);
+        let green = simulate((0, 255, 0), CvdKind::Tritanopia, 1., CvdModel::Brettel);
+        assert!(blue.2.abs_diff(blue.1) < 80, "{:?}", blue);
+        assert!(green.1.abs_diff(green.2) < 80, "{:?}", green);
+    }
+
+    #[test]
+    fn test_check_palette() {
+        let palette = [
+            Color::from("#d62728"),
+            Color::from("#2ca02c"),
+            Color::from("#1f77b4"),
+        ];
+        let report = check_palette(&palette, 10.);
+        assert_eq!(report.visions.len(), 4);
+        assert!(!report.is_safe());
+        let normal = report.vision(None).unwrap();
+        assert!(normal.collisions.is_empty());
+        assert!(report.min_distance() < normal.min_distance);
+
+        let report = check_palette_with(&palette, 10., &[CvdKind::Tritanopia]);
+        assert_eq!(report.visions.len(), 2);
+        assert!(report.vision(Some(CvdKind::Protanopia)).is_none());
+
+        // blue and orange are safe
+        let palette = [Color::from("#0072b2"), Color::from("#e69f00")];
+        assert!(check_palette(&palette, 10.).is_safe());
+
+        let report = check_palette(&palette[..1], 10.);
+        assert!(report.is_safe());
+        assert_eq!(report.min_distance(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_daltonize() {
+        for kind in CvdKind::DICHROMACIES {
+            assert_eq!(daltonize((255, 255, 255), kind, 1.), (255, 255, 255));
+            assert_eq!(daltonize((200, 30, 90), kind, 0.), (200, 30, 90));
+        }
+        assert_eq!(
+            daltonize((200, 30, 90), CvdKind::Achromatopsia, 1.),
+            (200, 30, 90)
+        );
+    }
+}
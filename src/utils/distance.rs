@@ -1,13 +1,12 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
 use crate::Color;
 
 /// Calc simple Euclidean distance between two colors with the same mode
 ///
 /// the default mode is `lab`
 pub fn distance(c1: &Color, c2: &Color, mode: Option<&str>) -> f64 {
-    let mode = match mode {
-        Some(mode) => mode,
-        None => "lab",
-    };
+    let mode = mode.unwrap_or("lab");
 
     let c1 = c1.mode(mode);
     let c2 = c2.mode(mode);
@@ -19,6 +18,130 @@ pub fn distance(c1: &Color, c2: &Color, mode: Option<&str>) -> f64 {
     sum_sq.sqrt()
 }
 
+/// Calc the CIEDE2000 color difference between two colors
+///
+/// <https://en.wikipedia.org/wiki/Color_difference#CIEDE2000>
+///
+/// ```
+/// use chroma_rust::{delta_e, Color};
+///
+/// let c1 = Color::from("#ededee");
+/// let c2 = Color::from("#edeeed");
+/// assert!(delta_e(&c1, &c2) < 2.);
+/// ```
+pub fn delta_e(c1: &Color, c2: &Color) -> f64 {
+    ciede2000(c1.lab(), c2.lab())
+}
+
+/// The formula of a Delta E color difference
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DeltaE {
+    /// Euclidean distance in CIE Lab
+    Cie76,
+    /// CIE94 with the graphic arts weights
+    Cie94,
+    #[default]
+    Ciede2000,
+}
+
+/// Calc the color difference between two colors with the given formula
+///
+/// ```
+/// use chroma_rust::{delta_e_with, Color, DeltaE};
+///
+/// let c1 = Color::from("#ededee");
+/// let c2 = Color::from("#edeeed");
+/// assert!(delta_e_with(&c1, &c2, DeltaE::Cie76) < 2.);
+/// assert!(delta_e_with(&c1, &c2, DeltaE::Cie94) < 2.);
+/// ```
+pub fn delta_e_with(c1: &Color, c2: &Color, metric: DeltaE) -> f64 {
+    match metric {
+        DeltaE::Cie76 => distance(c1, c2, Some("lab")),
+        DeltaE::Cie94 => cie94(c1.lab(), c2.lab()),
+        DeltaE::Ciede2000 => delta_e(c1, c2),
+    }
+}
+
+/// CIE94 difference between two CIE Lab colors, the first one being the reference
+///
+/// <https://en.wikipedia.org/wiki/Color_difference#CIE94>
+pub(crate) fn cie94(lab1: (f64, f64, f64), lab2: (f64, f64, f64)) -> f64 {
+    let (l1, a1, b1) = lab1;
+    let (l2, a2, b2) = lab2;
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let dl = l1 - l2;
+    let dc = c1 - c2;
+    let dh_sq = ((a1 - a2).powi(2) + (b1 - b2).powi(2) - dc.powi(2)).max(0.);
+    let sc = 1. + 0.045 * c1;
+    let sh = 1. + 0.015 * c1;
+
+    (dl.powi(2) + (dc / sc).powi(2) + dh_sq / sh.powi(2)).sqrt()
+}
+
+/// CIEDE2000 difference between two CIE Lab colors
+///
+/// <http://www2.ece.rochester.edu/~gsharma/ciede2000/ciede2000noteCRNA.pdf>
+pub(crate) fn ciede2000(lab1: (f64, f64, f64), lab2: (f64, f64, f64)) -> f64 {
+    let (l1, a1, b1) = lab1;
+    let (l2, a2, b2) = lab2;
+
+    let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.;
+    let g = 0.5 * (1. - (c_mean.powi(7) / (c_mean.powi(7) + 25_f64.powi(7))).sqrt());
+    let a1p = a1 * (1. + g);
+    let a2p = a2 * (1. + g);
+    let c1p = a1p.hypot(b1);
+    let c2p = a2p.hypot(b2);
+    let hue = |a: f64, b: f64| {
+        if a == 0. && b == 0. {
+            0.
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.)
+        }
+    };
+    let h1p = hue(a1p, b1);
+    let h2p = hue(a2p, b2);
+
+    let dl = l2 - l1;
+    let dc = c2p - c1p;
+    let dh = if c1p * c2p == 0. {
+        0.
+    } else if (h2p - h1p).abs() <= 180. {
+        h2p - h1p
+    } else if h2p <= h1p {
+        h2p - h1p + 360.
+    } else {
+        h2p - h1p - 360.
+    };
+    let dh = 2. * (c1p * c2p).sqrt() * (dh / 2.).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.;
+    let cp_mean = (c1p + c2p) / 2.;
+    let hp_mean = if c1p * c2p == 0. {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180. {
+        (h1p + h2p) / 2.
+    } else if h1p + h2p < 360. {
+        (h1p + h2p + 360.) / 2.
+    } else {
+        (h1p + h2p - 360.) / 2.
+    };
+
+    let t = 1. - 0.17 * (hp_mean - 30.).to_radians().cos()
+        + 0.24 * (2. * hp_mean).to_radians().cos()
+        + 0.32 * (3. * hp_mean + 6.).to_radians().cos()
+        - 0.20 * (4. * hp_mean - 63.).to_radians().cos();
+    let d_theta = 30. * (-((hp_mean - 275.) / 25.).powi(2)).exp();
+    let rc = 2. * (cp_mean.powi(7) / (cp_mean.powi(7) + 25_f64.powi(7))).sqrt();
+    let sl = 1. + 0.015 * (l_mean - 50.).powi(2) / (20. + (l_mean - 50.).powi(2)).sqrt();
+    let sc = 1. + 0.045 * cp_mean;
+    let sh = 1. + 0.015 * cp_mean * t;
+    let rt = -(2. * d_theta).to_radians().sin() * rc;
+
+    ((dl / sl).powi(2) + (dc / sc).powi(2) + (dh / sh).powi(2) + rt * (dc / sc) * (dh / sh)).sqrt()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -47,4 +170,50 @@ mod tests {
         assert_eq!(distance(&c1, &c2, Some("rgb")), 255.0);
         assert_eq!(distance(&c1, &c2, Some("lab")), 96.94758206572062);
     }
+
+    #[test]
+    fn test_ciede2000() {
+        // from the test data of Sharma et al.
+        let pairs = [
+            ((50., 2.6772, -79.7751), (50., 0., -82.7485), 2.0425),
+            ((50., 3.1571, -77.2803), (50., 0., -82.7485), 2.8615),
+            ((50., 2.5, 0.), (73., 25., -18.), 27.1492),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            ((50., 2.5, 0.), (50., 0., -2.5), 4.3065),
+            ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for (lab1, lab2, expected) in pairs {
+            assert!((ciede2000(lab1, lab2) - expected).abs() < 1e-4);
+            assert!((ciede2000(lab2, lab1) - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_delta_e() {
+        let c = Color::from("#ff0000");
+        assert_eq!(delta_e(&c, &c), 0.);
+        assert!(delta_e(&c, &Color::from("#00ff00")) > 80.);
+    }
+
+    #[test]
+    fn test_cie94() {
+        assert_eq!(cie94((50., 0., 0.), (40., 0., 0.)), 10.);
+        assert!((cie94((50., 2.5, 0.), (73., 25., -18.)) - 34.6892).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_delta_e_with() {
+        let c1 = Color::from("#ff0000");
+        let c2 = Color::from("#00ff00");
+        assert_eq!(
+            delta_e_with(&c1, &c2, DeltaE::Cie76),
+            distance(&c1, &c2, None)
+        );
+        assert_eq!(delta_e_with(&c1, &c2, DeltaE::default()), delta_e(&c1, &c2));
+        assert!(delta_e_with(&c1, &c2, DeltaE::Cie94) < delta_e_with(&c1, &c2, DeltaE::Cie76));
+    }
 }
@@ -0,0 +1,156 @@
+//! The float methods of `std` for `no_std`, implemented with libm.
+//!
+//! With `std` the inherent `f64` methods take precedence, so this trait is
+//! only imported without it. Tests link `std` even without the feature, so
+//! they use the inherent methods too, and the trait is compared with them here.
+
+pub(crate) trait Float {
+    fn powf(self, n: f64) -> f64;
+    fn powi(self, n: i32) -> f64;
+    fn sqrt(self) -> f64;
+    fn cbrt(self) -> f64;
+    fn ln(self) -> f64;
+    fn exp(self) -> f64;
+    fn exp_m1(self) -> f64;
+    fn sin(self) -> f64;
+    fn cos(self) -> f64;
+    fn acos(self) -> f64;
+    fn atan2(self, other: f64) -> f64;
+    fn hypot(self, other: f64) -> f64;
+    fn round(self) -> f64;
+    fn floor(self) -> f64;
+    fn ln_1p(self) -> f64;
+    fn rem_euclid(self, rhs: f64) -> f64;
+}
+
+impl Float for f64 {
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn exp_m1(self) -> f64 {
+        libm::expm1(self)
+    }
+
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    fn acos(self) -> f64 {
+        libm::acos(self)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    fn ln_1p(self) -> f64 {
+        libm::log1p(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = self % rhs;
+        if r < 0. {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+
+    fn assert_close(shim: f64, std: f64) {
+        assert!(
+            shim == std || (shim - std).abs() <= 1e-12 * std.abs().max(1.),
+            "{} != {}",
+            shim,
+            std
+        );
+    }
+
+    #[test]
+    fn test_float_matches_std() {
+        let values: [f64; 11] = [-2.5, -1., -0.3, 0., 0.04045, 0.5, 1., 2., 7.75, 100., 359.9];
+        for x in values {
+            assert_close(Float::sqrt(x.abs()), x.abs().sqrt());
+            assert_close(Float::cbrt(x), x.cbrt());
+            assert_close(Float::exp(x), x.exp());
+            assert_close(Float::exp_m1(x), x.exp_m1());
+            assert_close(Float::sin(x), x.sin());
+            assert_close(Float::cos(x), x.cos());
+            assert_close(Float::round(x), x.round());
+            assert_close(Float::floor(x), x.floor());
+            assert_close(Float::atan2(x, 0.7), x.atan2(0.7));
+            assert_close(Float::hypot(x, 3.), x.hypot(3.));
+            assert_close(Float::powf(x.abs(), 2.4), x.abs().powf(2.4));
+            assert_close(Float::powf(x.abs(), 1. / 3.), x.abs().powf(1. / 3.));
+            for n in [-2, 0, 1, 2, 3, 7] {
+                assert_close(Float::powi(x, n), x.powi(n));
+            }
+            if x > 0. {
+                assert_close(Float::ln(x), x.ln());
+            }
+            if x > -1. {
+                assert_close(Float::ln_1p(x), x.ln_1p());
+            }
+            if (-1. ..=1.).contains(&x) {
+                assert_close(Float::acos(x), x.acos());
+            }
+        }
+    }
+
+    #[test]
+    fn test_rem_euclid() {
+        let values: [f64; 10] = [-721., -360., -30., -1e-15, 0., 30., 359.9, 360., 400., 1e9];
+        for x in values {
+            for rhs in [360., -360., 1.] {
+                assert_eq!(
+                    Float::rem_euclid(x, rhs),
+                    x.rem_euclid(rhs),
+                    "{} % {}",
+                    x,
+                    rhs
+                );
+            }
+        }
+    }
+}
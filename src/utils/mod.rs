@@ -1,6 +1,9 @@
 pub mod conversion;
+pub mod cvd;
 pub mod distance;
 mod helper;
+#[cfg(not(feature = "std"))]
+pub(crate) mod math;
 pub mod parser;
 pub mod valid;
 
@@ -0,0 +1,117 @@
+use alloc::{string::ToString, vec::Vec};
+use core::str::FromStr;
+
+use super::ParseColorError;
+
+/// Parse the channels of a CSS color function, e.g. `oklch(70% 0.1 120 / 0.5)`.
+///
+/// Channels may be separated by commas or spaces. A percentage is resolved
+/// against the reference range of its channel in `percent_refs`. The alpha is
+/// either a fourth channel or after a slash, and is clamped to `0..1`.
+///
+/// Returns the three channels and the alpha.
+pub(crate) fn parse_channels(
+    str: &str,
+    prefix: &str,
+    percent_refs: [f64; 3],
+) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    let input = str;
+    let str = str
+        .trim()
+        .replace(prefix, "")
+        .replace(['(', ')', '°'], "")
+        .replace("deg", "")
+        .replace(',', " ");
+    let (channels, slash_alpha) = match str.split_once('/') {
+        Some((channels, alpha)) => (channels.to_string(), Some(parse_number(alpha.trim(), 1.)?)),
+        None => (str, None),
+    };
+    let channels: Vec<&str> = channels.split_whitespace().collect();
+    if !(3..=4).contains(&channels.len()) {
+        return Err(ParseColorError::WrongChannelCount(input.to_string()));
+    }
+    let v = channels
+        .iter()
+        .zip(percent_refs.iter().chain(&[1.]))
+        .map(|(s, &percent_ref)| parse_number(s, percent_ref))
+        .collect::<Result<Vec<f64>, _>>()?;
+    let alpha = match (v.get(3), slash_alpha) {
+        (Some(_), Some(_)) => return Err(ParseColorError::WrongChannelCount(input.to_string())),
+        (Some(&alpha), None) | (None, Some(alpha)) => alpha,
+        (None, None) => 1.,
+    };
+    Ok((v[0], v[1], v[2], alpha.clamp(0., 1.)))
+}
+
+/// Parse a finite number, or a percentage of `percent_ref`.
+pub(crate) fn parse_number(s: &str, percent_ref: f64) -> Result<f64, ParseColorError> {
+    let invalid = || ParseColorError::InvalidNumber(s.to_string());
+    let number = match s.strip_suffix('%') {
+        Some(percent) => f64::from_str(percent).map_err(|_| invalid())? / 100. * percent_ref,
+        None => f64::from_str(s).map_err(|_| invalid())?,
+    };
+    if number.is_finite() {
+        Ok(number)
+    } else {
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_channels() {
+        let refs = [1., 0.4, 1.];
+        assert_eq!(
+            parse_channels("oklch(0.5 0.1 120)", "oklch", refs),
+            Ok((0.5, 0.1, 120., 1.))
+        );
+        assert_eq!(
+            parse_channels("oklch(50% 0.1 120deg / 0.5)", "oklch", refs),
+            Ok((0.5, 0.1, 120., 0.5))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5, 50%, 120)", "oklch", refs),
+            Ok((0.5, 0.2, 120., 1.))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5, 0.1, 120, 50%)", "oklch", refs),
+            Ok((0.5, 0.1, 120., 0.5))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5 0.1 120 / 2)", "oklch", refs),
+            Ok((0.5, 0.1, 120., 1.))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5 0.1 120 / -50%)", "oklch", refs),
+            Ok((0.5, 0.1, 120., 0.))
+        );
+    }
+
+    #[test]
+    fn test_parse_channels_invalid() {
+        let refs = [1., 0.4, 1.];
+        assert_eq!(
+            parse_channels("oklch(0.5 0.1)", "oklch", refs),
+            Err(ParseColorError::WrongChannelCount(
+                "oklch(0.5 0.1)".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5 x 120)", "oklch", refs),
+            Err(ParseColorError::InvalidNumber("x".to_string()))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5 0.1 1e400)", "oklch", refs),
+            Err(ParseColorError::InvalidNumber("1e400".to_string()))
+        );
+        assert_eq!(
+            parse_channels("oklch(0.5 0.1 120 1 / 0.5)", "oklch", refs),
+            Err(ParseColorError::WrongChannelCount(
+                "oklch(0.5 0.1 120 1 / 0.5)".to_string()
+            ))
+        );
+    }
+}
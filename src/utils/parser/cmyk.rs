@@ -1,22 +1,21 @@
-use std::str::FromStr;
+use alloc::{string::ToString, vec::Vec};
+
+use super::{channels::parse_number, ParseColorError};
 
 /// Parse a string as a color in the cmyk format.
-pub fn parse_cmyk_str(str: &str) -> (f64, f64, f64, f64) {
-    let v: Vec<f64> = str
+pub fn parse_cmyk_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    let v = str
         .trim()
-        .replace(" ", "")
+        .replace(' ', "")
         .replace("cmyk(", "")
-        .replace(")", "")
-        .split(",")
-        .map(|s| {
-            if s.contains('%') {
-                f64::from_str(s.replace("%", "").as_str()).unwrap() / 100.
-            } else {
-                f64::from_str(s).unwrap()
-            }
-        })
-        .collect();
-    (v[0], v[1], v[2], v[3])
+        .replace(')', "")
+        .split(',')
+        .map(|s| parse_number(s, 1.))
+        .collect::<Result<Vec<f64>, _>>()?;
+    match v[..] {
+        [c, m, y, k] => Ok((c, m, y, k)),
+        _ => Err(ParseColorError::WrongChannelCount(str.to_string())),
+    }
 }
 
 #[cfg(test)]
@@ -25,16 +24,16 @@ mod tests {
 
     #[test]
     fn test_parse_cmyk_str() {
-        let cmyk = parse_cmyk_str("cmyk(95%, 80%, 0, 0)");
+        let cmyk = parse_cmyk_str("cmyk(95%, 80%, 0, 0)").unwrap();
         assert_eq!(cmyk, (0.95, 0.8, 0., 0.));
 
-        let cmyk = parse_cmyk_str("cmyk(0.95, 0.8, 0, 0)");
+        let cmyk = parse_cmyk_str("cmyk(0.95, 0.8, 0, 0)").unwrap();
         assert_eq!(cmyk, (0.95, 0.8, 0., 0.));
 
-        let cmyk = parse_cmyk_str("cmyk(0, 0, 0, 0)");
+        let cmyk = parse_cmyk_str("cmyk(0, 0, 0, 0)").unwrap();
         assert_eq!(cmyk, (0., 0., 0., 0.));
 
-        let cmyk = parse_cmyk_str("cmyk(0, 0, 0, 1)");
+        let cmyk = parse_cmyk_str("cmyk(0, 0, 0, 1)").unwrap();
         assert_eq!(cmyk, (0., 0., 0., 1.));
     }
 }
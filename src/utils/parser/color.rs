@@ -0,0 +1,44 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{channels::parse_number, ParseColorError};
+
+/// Parse a string as a color in the CSS `color()` format.
+///
+/// Returns the name of the color space, the channels and the alpha, clamped to `0..1`.
+pub fn parse_color_str(str: &str) -> Result<(String, f64, f64, f64, f64), ParseColorError> {
+    let input = str;
+    let str = str.trim().replace("color(", "").replace(')', "");
+    let (channels, alpha) = match str.split_once('/') {
+        Some((channels, alpha)) => (channels.to_string(), parse_number(alpha.trim(), 1.)?),
+        None => (str, 1.),
+    };
+    let mut iter = channels.split_whitespace();
+    let name = iter.next().unwrap_or_default().to_string();
+    let v = iter
+        .map(|s| parse_number(s, 1.))
+        .collect::<Result<Vec<f64>, _>>()?;
+    match v[..] {
+        [r, g, b] => Ok((name, r, g, b, alpha.clamp(0., 1.))),
+        _ => Err(ParseColorError::WrongChannelCount(input.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_str() {
+        let color = parse_color_str("color(display-p3 1 0.5 0)").unwrap();
+        assert_eq!(color, ("display-p3".to_string(), 1., 0.5, 0., 1.));
+
+        let color = parse_color_str("color(rec2020 100% 50% 0% / 0.5)").unwrap();
+        assert_eq!(color, ("rec2020".to_string(), 1., 0.5, 0., 0.5));
+
+        let color = parse_color_str("color(srgb 0 0 1/50%)").unwrap();
+        assert_eq!(color, ("srgb".to_string(), 0., 0., 1., 0.5));
+    }
+}
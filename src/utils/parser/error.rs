@@ -0,0 +1,33 @@
+use alloc::string::String;
+use core::{error, fmt};
+
+/// The error returned when a string can't be parsed as a color.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseColorError {
+    /// Neither a hex color, a color function nor a named color
+    UnknownColor(String),
+    /// A hex color with a wrong length or digits
+    InvalidHex(String),
+    /// A channel which isn't a number or a percentage
+    InvalidNumber(String),
+    /// A color function with too few or too many channels
+    WrongChannelCount(String),
+    /// A `color()` function in an unknown RGB color space
+    UnknownColorSpace(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::UnknownColor(s) => write!(f, "Color not found: `{}`", s),
+            ParseColorError::InvalidHex(s) => write!(f, "Invalid hex color: `{}`", s),
+            ParseColorError::InvalidNumber(s) => write!(f, "Invalid number: `{}`", s),
+            ParseColorError::WrongChannelCount(s) => {
+                write!(f, "Wrong number of channels: `{}`", s)
+            }
+            ParseColorError::UnknownColorSpace(s) => write!(f, "Color space not found: `{}`", s),
+        }
+    }
+}
+
+impl error::Error for ParseColorError {}
@@ -1,23 +1,11 @@
-use std::str::FromStr;
-
-/// Parse a string as a color in the HSL format.
-pub fn parse_hsl_str(str: &str) -> (f64, f64, f64) {
-    let v: Vec<f64> = str
-        .trim()
-        .replace(" ", "")
-        .replace("°", "")
-        .replace("hsl(", "")
-        .replace(")", "")
-        .split(",")
-        .map(|s| {
-            if s.contains('%') {
-                f64::from_str(s.replace("%", "").as_str()).unwrap() / 100.
-            } else {
-                f64::from_str(s).unwrap()
-            }
-        })
-        .collect();
-    (v[0], v[1], v[2])
+use super::{channels::parse_channels, ParseColorError};
+
+/// Parse a string as a color in the HSL format, e.g. `hsl(0, 100%, 50%)` or
+/// `hsla(0 100% 50% / 0.5)`.
+///
+/// Returns the hue, saturation, lightness and alpha.
+pub fn parse_hsl_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(&str.replace("hsla", "hsl"), "hsl", [1., 1., 1.])
 }
 
 #[cfg(test)]
@@ -26,16 +14,22 @@ mod tests {
 
     #[test]
     fn test_parse_hsl_str() {
-        let hsl = parse_hsl_str("hsl(0, 0%, 0%)");
-        assert_eq!(hsl, (0., 0., 0.));
+        let hsl = parse_hsl_str("hsl(0, 0%, 0%)").unwrap();
+        assert_eq!(hsl, (0., 0., 0., 1.));
+
+        let hsl = parse_hsl_str("hsl(0, 0, 0)").unwrap();
+        assert_eq!(hsl, (0., 0., 0., 1.));
+
+        let hsl = parse_hsl_str("hsl(0, 100%, 100%)").unwrap();
+        assert_eq!(hsl, (0., 1., 1., 1.));
 
-        let hsl = parse_hsl_str("hsl(0, 0, 0)");
-        assert_eq!(hsl, (0., 0., 0.));
+        let hsl = parse_hsl_str("hsl(0, 100, 100)").unwrap();
+        assert_eq!(hsl, (0., 100., 100., 1.));
 
-        let hsl = parse_hsl_str("hsl(0, 100%, 100%)");
-        assert_eq!(hsl, (0., 1., 1.));
+        let hsl = parse_hsl_str("hsla(120, 100%, 50%, 0.5)").unwrap();
+        assert_eq!(hsl, (120., 1., 0.5, 0.5));
 
-        let hsl = parse_hsl_str("hsl(0, 100, 100)");
-        assert_eq!(hsl, (0., 100., 100.));
+        let hsl = parse_hsl_str("hsl(120deg 100% 50% / 0.5)").unwrap();
+        assert_eq!(hsl, (120., 1., 0.5, 0.5));
     }
 }
@@ -0,0 +1,36 @@
+use super::{channels::parse_channels, ParseColorError};
+
+/// Parse a string as a color in the HSLuv format.
+///
+/// Returns the hue, saturation, lightness and alpha, the saturation and
+/// lightness in the range [0, 100].
+pub fn parse_hsluv_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "hsluv", [1., 100., 100.])
+}
+
+/// Parse a string as a color in the HPLuv format.
+///
+/// Returns the hue, saturation, lightness and alpha.
+pub fn parse_hpluv_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "hpluv", [1., 100., 100.])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hsluv_str() {
+        let hsluv = parse_hsluv_str("hsluv(12.18, 100, 53.24)").unwrap();
+        assert_eq!(hsluv, (12.18, 100., 53.24, 1.));
+
+        let hsluv = parse_hsluv_str("hsluv(12.18deg 100% 50% / 0.5)").unwrap();
+        assert_eq!(hsluv, (12.18, 100., 50., 0.5));
+    }
+
+    #[test]
+    fn test_parse_hpluv_str() {
+        let hpluv = parse_hpluv_str("hpluv(12.18, 50%, 53.24)").unwrap();
+        assert_eq!(hpluv, (12.18, 50., 53.24, 1.));
+    }
+}
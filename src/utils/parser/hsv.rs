@@ -1,23 +1,11 @@
-use std::str::FromStr;
+use super::{channels::parse_channels, ParseColorError};
 
-/// Parse a string as a color in the HSV format.
-pub fn parse_hsv_str(str: &str) -> (f64, f64, f64) {
-    let v: Vec<f64> = str
-        .trim()
-        .replace(" ", "")
-        .replace("°", "")
-        .replace("hsv(", "")
-        .replace(")", "")
-        .split(",")
-        .map(|s| {
-            if s.contains('%') {
-                f64::from_str(s.replace("%", "").as_str()).unwrap() / 100.
-            } else {
-                f64::from_str(s).unwrap()
-            }
-        })
-        .collect();
-    (v[0], v[1], v[2])
+/// Parse a string as a color in the HSV format, e.g. `hsv(0, 100%, 100%)` or
+/// `hsv(0 1 1 / 0.5)`.
+///
+/// Returns the hue, saturation, value and alpha.
+pub fn parse_hsv_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "hsv", [1., 1., 1.])
 }
 
 #[cfg(test)]
@@ -26,16 +14,19 @@ mod tests {
 
     #[test]
     fn test_parse_hsv_str() {
-        let hsv = parse_hsv_str("hsv(0, 0%, 0%)");
-        assert_eq!(hsv, (0., 0., 0.));
+        let hsv = parse_hsv_str("hsv(0, 0%, 0%)").unwrap();
+        assert_eq!(hsv, (0., 0., 0., 1.));
 
-        let hsv = parse_hsv_str("hsv(0, 0, 0)");
-        assert_eq!(hsv, (0., 0., 0.));
+        let hsv = parse_hsv_str("hsv(0, 0, 0)").unwrap();
+        assert_eq!(hsv, (0., 0., 0., 1.));
 
-        let hsv = parse_hsv_str("hsv(300°, 100%, 100%)");
-        assert_eq!(hsv, (300., 1., 1.));
+        let hsv = parse_hsv_str("hsv(300°, 100%, 100%)").unwrap();
+        assert_eq!(hsv, (300., 1., 1., 1.));
 
-        let hsv = parse_hsv_str("hsv(0, 100, 100)");
-        assert_eq!(hsv, (0., 100., 100.));
+        let hsv = parse_hsv_str("hsv(0, 100, 100)").unwrap();
+        assert_eq!(hsv, (0., 100., 100., 1.));
+
+        let hsv = parse_hsv_str("hsv(120 1 1 / 0.5)").unwrap();
+        assert_eq!(hsv, (120., 1., 1., 0.5));
     }
 }
@@ -0,0 +1,25 @@
+use super::{channels::parse_channels, ParseColorError};
+
+/// Parse a string as a color in the HWB format.
+///
+/// Returns the hue, whiteness, blackness and alpha.
+pub fn parse_hwb_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "hwb", [1., 1., 1.])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hwb_str() {
+        let hwb = parse_hwb_str("hwb(90, 40%, 20%)").unwrap();
+        assert_eq!(hwb, (90., 0.4, 0.2, 1.));
+
+        let hwb = parse_hwb_str("hwb(90deg 40% 20% / 0.5)").unwrap();
+        assert_eq!(hwb, (90., 0.4, 0.2, 0.5));
+
+        let hwb = parse_hwb_str("hwb(0 0 0)").unwrap();
+        assert_eq!(hwb, (0., 0., 0., 1.));
+    }
+}
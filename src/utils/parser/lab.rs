@@ -1,16 +1,11 @@
-use std::str::FromStr;
+use super::{channels::parse_channels, ParseColorError};
 
-/// Parse a string as a color in the LAB format.
-pub fn parse_lab_str(str: &str) -> (f64, f64, f64) {
-    let v: Vec<f64> = str
-        .trim()
-        .replace(" ", "")
-        .replace("lab(", "")
-        .replace(")", "")
-        .split(",")
-        .map(|s| f64::from_str(s).unwrap())
-        .collect();
-    (v[0], v[1], v[2])
+/// Parse a string as a color in the CIE Lab format, e.g. `lab(50, 20, -30)`
+/// or `lab(50% 20 -30 / 0.5)`.
+///
+/// Returns the lightness, a, b and alpha.
+pub fn parse_lab_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "lab", [100., 125., 125.])
 }
 
 #[cfg(test)]
@@ -19,10 +14,13 @@ mod tests {
 
     #[test]
     fn test_parse_lab_str() {
-        let lab = parse_lab_str("lab(0, 0, 0)");
-        assert_eq!(lab, (0., 0., 0.));
+        let lab = parse_lab_str("lab(0, 0, 0)").unwrap();
+        assert_eq!(lab, (0., 0., 0., 1.));
 
-        let lab = parse_lab_str("lab(100, 0, 0)");
-        assert_eq!(lab, (100., 0., 0.));
+        let lab = parse_lab_str("lab(100, 0, 0)").unwrap();
+        assert_eq!(lab, (100., 0., 0., 1.));
+
+        let lab = parse_lab_str("lab(50% -20 100% / 0.5)").unwrap();
+        assert_eq!(lab, (50., -20., 125., 0.5));
     }
 }
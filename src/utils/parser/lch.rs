@@ -0,0 +1,22 @@
+use super::{channels::parse_channels, ParseColorError};
+
+/// Parse a string as a color in the LCh format.
+///
+/// Returns the lightness, chroma, hue and alpha.
+pub fn parse_lch_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "lch", [100., 150., 1.])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lch_str() {
+        let lch = parse_lch_str("lch(50, 30, 120)").unwrap();
+        assert_eq!(lch, (50., 30., 120., 1.));
+
+        let lch = parse_lch_str("lch(50% 100% 120deg / 0.5)").unwrap();
+        assert_eq!(lch, (50., 150., 120., 0.5));
+    }
+}
@@ -0,0 +1,35 @@
+use super::{channels::parse_channels, ParseColorError};
+
+/// Parse a string as a color in the CIE-L*uv format.
+///
+/// Returns the lightness, u, v and alpha.
+pub fn parse_luv_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "luv", [100., 1., 1.])
+}
+
+/// Parse a string as a color in the CIE LCh(uv) format.
+///
+/// Returns the lightness, chroma, hue and alpha.
+pub fn parse_lchuv_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "lchuv", [100., 1., 1.])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_luv_str() {
+        let luv = parse_luv_str("luv(53.24, 175.01, 37.76)").unwrap();
+        assert_eq!(luv, (53.24, 175.01, 37.76, 1.));
+
+        let luv = parse_luv_str("luv(50% -10 20 / 0.5)").unwrap();
+        assert_eq!(luv, (50., -10., 20., 0.5));
+    }
+
+    #[test]
+    fn test_parse_lchuv_str() {
+        let lchuv = parse_lchuv_str("lchuv(53.24, 179.04, 12.18)").unwrap();
+        assert_eq!(lchuv, (53.24, 179.04, 12.18, 1.));
+    }
+}
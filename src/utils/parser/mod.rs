@@ -1,11 +1,26 @@
+mod channels;
 mod cmyk;
+mod color;
+mod error;
 mod hsl;
+mod hsluv;
 mod hsv;
+mod hwb;
 mod lab;
+mod lch;
+mod luv;
+mod oklab;
 mod rgb;
 
 pub use cmyk::parse_cmyk_str;
+pub use color::parse_color_str;
+pub use error::ParseColorError;
 pub use hsl::parse_hsl_str;
+pub use hsluv::{parse_hpluv_str, parse_hsluv_str};
 pub use hsv::parse_hsv_str;
+pub use hwb::parse_hwb_str;
 pub use lab::parse_lab_str;
+pub use lch::parse_lch_str;
+pub use luv::{parse_lchuv_str, parse_luv_str};
+pub use oklab::{parse_oklab_str, parse_oklch_str};
 pub use rgb::{parse_rgb_str, parse_rgba_str};
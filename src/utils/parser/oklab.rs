@@ -0,0 +1,38 @@
+use super::{channels::parse_channels, ParseColorError};
+
+/// Parse a string as a color in the OKLab format.
+///
+/// Returns the lightness, a, b and alpha.
+pub fn parse_oklab_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "oklab", [1., 0.4, 0.4])
+}
+
+/// Parse a string as a color in the OKLCh format.
+///
+/// Returns the lightness, chroma, hue and alpha.
+pub fn parse_oklch_str(str: &str) -> Result<(f64, f64, f64, f64), ParseColorError> {
+    parse_channels(str, "oklch", [1., 0.4, 1.])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_oklab_str() {
+        let oklab = parse_oklab_str("oklab(0.5, 0.1, -0.1)").unwrap();
+        assert_eq!(oklab, (0.5, 0.1, -0.1, 1.));
+
+        let oklab = parse_oklab_str("oklab(50% 25% -25% / 50%)").unwrap();
+        assert_eq!(oklab, (0.5, 0.1, -0.1, 0.5));
+    }
+
+    #[test]
+    fn test_parse_oklch_str() {
+        let oklch = parse_oklch_str("oklch(0.7 0.1 120)").unwrap();
+        assert_eq!(oklch, (0.7, 0.1, 120., 1.));
+
+        let oklch = parse_oklch_str("oklch(70% 25% 120deg / 0.5)").unwrap();
+        assert_eq!(oklch, (0.7, 0.1, 120., 0.5));
+    }
+}
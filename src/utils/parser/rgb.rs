@@ -1,35 +1,22 @@
-use std::str::FromStr;
-
-/// Parse a string as a color in the RGB format.
-pub fn parse_rgb_str(str: &str) -> (u8, u8, u8, f64) {
-    let v_u8: Vec<u8> = str
-        .trim()
-        .replace(" ", "")
-        .replace("rgb(", "")
-        .replace(")", "")
-        .split(",")
-        .map(|s| s.parse::<f64>().unwrap().round() as u8)
-        .collect();
-    (v_u8[0], v_u8[1], v_u8[2], 1.)
+use super::{channels::parse_channels, ParseColorError};
+#[cfg(not(any(feature = "std", test)))]
+use crate::utils::math::Float;
+
+/// Parse a string as a color in the RGB format, e.g. `rgb(255, 0, 0)` or `rgb(255 0 0 / 0.5)`.
+///
+/// Like in CSS, it's the same as `rgba()` and takes an alpha too.
+pub fn parse_rgb_str(str: &str) -> Result<(u8, u8, u8, f64), ParseColorError> {
+    let (r, g, b, alpha) = parse_channels(str, "rgb", [255.; 3])?;
+    Ok((channel(r), channel(g), channel(b), alpha))
 }
 
-/// Parse a string as a color in the RGBA format.
-pub fn parse_rgba_str(str: &str) -> (u8, u8, u8, f64) {
-    let v: Vec<String> = str
-        .trim()
-        .replace(" ", "")
-        .replace("rgba(", "")
-        .replace(")", "")
-        .split(",")
-        .map(|s| s.to_string())
-        .collect();
-    let (r, g, b) = (
-        v[0].parse::<f64>().unwrap().round() as u8,
-        v[1].parse::<f64>().unwrap().round() as u8,
-        v[2].parse::<f64>().unwrap().round() as u8,
-    );
-    let alpha = f64::from_str(v[3].as_str()).unwrap();
-    (r, g, b, alpha)
+pub fn parse_rgba_str(str: &str) -> Result<(u8, u8, u8, f64), ParseColorError> {
+    let (r, g, b, alpha) = parse_channels(str, "rgba", [255.; 3])?;
+    Ok((channel(r), channel(g), channel(b), alpha))
+}
+
+fn channel(v: f64) -> u8 {
+    v.round() as u8
 }
 
 #[cfg(test)]
@@ -38,25 +25,31 @@ mod tests {
 
     #[test]
     fn test_parse_rgb_str() {
-        let rgb = parse_rgb_str("rgb(0, 0, 0)");
+        let rgb = parse_rgb_str("rgb(0, 0, 0)").unwrap();
         assert_eq!(rgb, (0, 0, 0, 1.));
 
-        let rgb = parse_rgb_str("rgb(255, 255, 255)");
+        let rgb = parse_rgb_str("rgb(255, 255, 255)").unwrap();
         assert_eq!(rgb, (255, 255, 255, 1.));
 
-        let rgb = parse_rgb_str("rgb(254, 255, 255, 0.5)");
-        assert_eq!(rgb, (254, 255, 255, 1.));
+        let rgb = parse_rgb_str("rgb(254, 255, 255, 0.5)").unwrap();
+        assert_eq!(rgb, (254, 255, 255, 0.5));
+
+        let rgb = parse_rgb_str("rgb(254 100% 0 / 0.5)").unwrap();
+        assert_eq!(rgb, (254, 255, 0, 0.5));
     }
 
     #[test]
     fn test_parse_rgba_str() {
-        let rgba = parse_rgba_str("rgba(0, 0, 0, 0)");
+        let rgba = parse_rgba_str("rgba(0, 0, 0, 0)").unwrap();
         assert_eq!(rgba, (0, 0, 0, 0.));
 
-        let rgba = parse_rgba_str("rgba(255, 255, 255, 1)");
+        let rgba = parse_rgba_str("rgba(255, 255, 255, 1)").unwrap();
         assert_eq!(rgba, (255, 255, 255, 1.));
 
-        let rgba = parse_rgba_str("rgba(255, 255, 255, 0.5)");
+        let rgba = parse_rgba_str("rgba(255, 255, 255, 0.5)").unwrap();
+        assert_eq!(rgba, (255, 255, 255, 0.5));
+
+        let rgba = parse_rgba_str("rgba(255 255 255 / 50%)").unwrap();
         assert_eq!(rgba, (255, 255, 255, 0.5));
     }
 }
@@ -9,27 +9,7 @@
 /// chroma_rust::valid("#FOOOOD");
 /// ```
 pub fn valid(str: &str) -> bool {
-    let valid = match str {
-        str if str.starts_with("#") => {
-            if str.len() == 4 || str.len() == 7 {
-                str.chars().skip(1).all(|c| c.is_ascii_hexdigit())
-            } else {
-                false
-            }
-        }
-        // TODO: check if rgb/rgba/hsl/hsla/hsv/hsva are valid
-        str if str.starts_with("rgba") => true,
-        str if str.starts_with("rgb") => true,
-        str if str.starts_with("lab") => true,
-        str if str.starts_with("hsl") => true,
-        str if str.starts_with("hsv") => true,
-        str if str.starts_with("cmyk") => true,
-        _ => match crate::W3CX11.get(str) {
-            Some(_) => true,
-            None => false,
-        },
-    };
-    valid
+    str.parse::<crate::Color>().is_ok()
 }
 
 #[cfg(test)]
@@ -47,6 +27,19 @@ mod tests {
         assert!(valid("#abc"));
     }
 
+    #[test]
+    fn test_valid_hex_alpha() {
+        assert!(valid("#abcd"));
+        assert!(valid("#aabbccdd"));
+    }
+
+    #[test]
+    fn test_valid_hex_prefix() {
+        assert!(valid("0xaabbcc"));
+        assert!(!valid("aabbcc"));
+        assert!(!valid("facade"));
+    }
+
     #[test]
     fn test_valid_hex_invalid() {
         assert!(!valid("#FOOOOD"));
@@ -72,11 +65,23 @@ mod tests {
         assert!(valid("lab(100, 0, 0)"));
     }
 
+    #[test]
+    fn test_valid_color_fn() {
+        assert!(valid("color(display-p3 1 0 0)"));
+        assert!(!valid("color(p3 1 0 0)"));
+    }
+
     #[test]
     fn test_valid_name() {
         assert!(valid("mediumspringgreen"));
     }
 
+    #[test]
+    fn test_valid_rgb_invalid() {
+        assert!(!valid("rgb(255, 255)"));
+        assert!(!valid("hsl(red, 50%, 50%)"));
+    }
+
     #[test]
     fn test_invalid() {
         assert!(!valid("invalid"));